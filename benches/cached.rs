@@ -0,0 +1,42 @@
+//! Compares the plain and cached interpolators on temporally coherent inputs.
+//!
+//! Run with `cargo bench --bench cached`
+
+fn main() {
+    #[cfg(not(feature = "no_std"))]
+    bench::run();
+
+    #[cfg(feature = "no_std")]
+    eprintln!("the cached bench requires std, build it without the `no_std` feature");
+}
+
+/// The interpolators being compared are not available without std
+#[cfg(not(feature = "no_std"))]
+mod bench {
+    use lineic::{CachedInterpolator, LinearInterpolator};
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    const ITERATIONS: u32 = 5_000_000;
+
+    pub fn run() {
+        let value_sets: Vec<_> = (0..256)
+            .map(|i| [f64::from(i), f64::from(255 - i)])
+            .collect();
+        let interpolator = LinearInterpolator::new(0.0..=1.0, &value_sets);
+        let cached = CachedInterpolator::new(interpolator.clone());
+
+        let plain = time(|s| interpolator.interpolate(s));
+        let cached = time(|s| cached.interpolate(s));
+        println!("plain: {plain:>8.2}ns/iter  cached: {cached:>8.2}ns/iter");
+    }
+
+    /// Time `f` over a slow, monotonic sweep of the range, returning the mean nanoseconds per call
+    fn time(f: impl Fn(f64) -> [f64; 2]) -> f64 {
+        let start = Instant::now();
+        for i in 0..ITERATIONS {
+            black_box(f(black_box(f64::from(i) / f64::from(ITERATIONS))));
+        }
+        start.elapsed().as_secs_f64() * 1e9 / f64::from(ITERATIONS)
+    }
+}
@@ -0,0 +1,44 @@
+//! Compares scalar and SIMD interpolation of `f32` value sets at several channel counts.
+//!
+//! Run with `cargo bench --features simd --bench simd`
+use lineic::InterpolationBucket;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 5_000_000;
+
+fn main() {
+    bench::<4>();
+    bench::<8>();
+    bench::<16>();
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn bench<const N: usize>() {
+    let lo: [f32; N] = std::array::from_fn(|i| i as f32);
+    let hi: [f32; N] = std::array::from_fn(|i| 255.0 - i as f32 * 2.0);
+    let bucket = InterpolationBucket::new((0.0f32, 1.0), lo, hi);
+
+    // Results should agree to within a few units in the last place
+    for i in 0..=100 {
+        let s = i as f32 / 100.0;
+        let scalar = bucket.interpolate(s);
+        let simd = bucket.interpolate_simd(s);
+        for (a, b) in scalar.iter().zip(simd) {
+            assert!((a - b).abs() <= f32::EPSILON * a.abs().max(1.0) * 4.0);
+        }
+    }
+
+    let scalar = time(|s| bucket.interpolate(s));
+    let simd = time(|s| bucket.interpolate_simd(s));
+    println!("N={N:<2}  scalar: {scalar:>8.2}ns/iter  simd: {simd:>8.2}ns/iter");
+}
+
+/// Time `f` over a sweep of inputs, returning the mean nanoseconds per call
+fn time<const N: usize>(f: impl Fn(f32) -> [f32; N]) -> f64 {
+    let start = Instant::now();
+    for i in 0..ITERATIONS {
+        black_box(f(black_box((i % 1000) as f32 / 1000.0)));
+    }
+    start.elapsed().as_nanos() as f64 / f64::from(ITERATIONS)
+}
@@ -1,5 +1,7 @@
 //! This example shows how to interpolate over a non-standard type  
 //! Here we create a `NumericUnicode` type that allows us to interpolate across unicode code points.
+//!
+//! Run with `cargo run --example custom_types --features derive`
 use lineic::LinearInterpolator;
 
 fn main() {
@@ -19,7 +21,10 @@ fn main() {
 
 /// A type that allows iterating over unicode code points without hitting invalid code points.
 /// This is just an example type used to demo the custom types feature.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+///
+/// `lineic::Numeric` is derived here, which delegates everything to the inner `u32`  
+/// The trait can also be implemented by hand for types that are not simple wrappers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, lineic::Numeric)]
 struct NumericUnicode(u32);
 impl NumericUnicode {
     /// Convert a slice of NumericUnicode values into a string
@@ -62,66 +67,3 @@ impl std::fmt::Display for NumericUnicode {
         write!(f, "{}", char::from(*self))
     }
 }
-
-// Implement the Numeric trait for NumericUnicode, so we can interpolate between them.
-impl lineic::Numeric for NumericUnicode {
-    // Some constants the interpolator will use
-    const MAX: Self = NumericUnicode(u32::MAX);
-    const ZERO: Self = NumericUnicode(0);
-    const ONE: Self = NumericUnicode(1);
-
-    // Get the absolute value of this number
-    fn abs(self) -> Self {
-        self
-    }
-
-    // Clamp this number between a minimum and maximum value
-    // Without panicking if max < min
-    fn clamp(self, min: Self, max: Self) -> Self {
-        Self(if min > max {
-            std::cmp::Ord::clamp(self.0, min.0, max.0)
-        } else {
-            std::cmp::Ord::clamp(self.0, max.0, min.0)
-        })
-    }
-
-    //
-    // These are methods instead of a trait requirement for better stdlib compatibility
-    //
-
-    fn from_usize(value: usize) -> Option<Self> {
-        u32::try_from(value).ok().map(Self)
-    }
-
-    fn into_f64(self) -> f64 {
-        self.0 as f64
-    }
-
-    fn from_f64(value: f64) -> Option<Self> {
-        if value < u32::MAX as f64 && value >= 0.0 {
-            Some(Self(value as u32))
-        } else {
-            None
-        }
-    }
-
-    //
-    // Checked arithmetic operations
-    //
-
-    fn checked_sub(self, other: Self) -> Option<Self> {
-        self.0.checked_sub(other.0).map(Self)
-    }
-
-    fn checked_add(self, other: Self) -> Option<Self> {
-        self.0.checked_add(other.0).map(Self)
-    }
-
-    fn checked_mul(self, other: Self) -> Option<Self> {
-        self.0.checked_mul(other.0).map(Self)
-    }
-
-    fn checked_div(self, other: Self) -> Option<Self> {
-        self.0.checked_div(other.0).map(Self)
-    }
-}
@@ -0,0 +1,157 @@
+//! Derive macro for `lineic::Numeric`.
+//!
+//! This crate is re-exported by `lineic` behind the `derive` feature, and should not be used directly.
+#![warn(clippy::pedantic)]
+#![warn(missing_docs)]
+#![allow(clippy::doc_comment_double_space_linebreaks)] // Trailing double-spaces are used for line breaks in the docs
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Derive `lineic::Numeric` for a single-field tuple struct wrapping an existing `Numeric` type.  
+/// All constants and methods are delegated to the inner field.
+///
+/// The type must still implement the supertraits of `Numeric` (`Clone`, `PartialOrd`, `Debug` and `Display`).
+///
+/// ```rust,ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, lineic::Numeric)]
+/// struct Meters(f64);
+/// ```
+#[proc_macro_derive(Numeric)]
+pub fn derive_numeric(input: TokenStream) -> TokenStream {
+    match parse_newtype(input) {
+        Ok((name, inner)) => expand(&name, &inner),
+        Err(message) => compile_error(message),
+    }
+}
+
+/// Report an error at the derive site
+fn compile_error(message: &str) -> TokenStream {
+    format!("compile_error!({message:?});").parse().unwrap()
+}
+
+/// Extract the name and inner type of a single-field tuple struct
+fn parse_newtype(input: TokenStream) -> Result<(String, String), &'static str> {
+    let mut tokens = input.into_iter().peekable();
+
+    // Skip attributes and visibility
+    let mut name = None;
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident.to_string() == "struct" => {
+                name = tokens.next();
+                break;
+            }
+            TokenTree::Ident(ident) if matches!(ident.to_string().as_str(), "enum" | "union") => {
+                return Err("Numeric can only be derived for tuple structs");
+            }
+            _ => {}
+        }
+    }
+    let Some(TokenTree::Ident(name)) = name else {
+        return Err("Numeric can only be derived for tuple structs");
+    };
+
+    let fields = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream()
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            return Err("Numeric cannot be derived for generic types");
+        }
+        _ => return Err("Numeric can only be derived for tuple structs"),
+    };
+
+    // Strip attributes and visibility from the field, leaving only its type
+    let mut fields = fields.into_iter().peekable();
+    let mut inner = Vec::new();
+    while let Some(token) = fields.next() {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == '#' && inner.is_empty() => {
+                fields.next();
+            }
+            TokenTree::Ident(ident) if ident.to_string() == "pub" && inner.is_empty() => {
+                if let Some(TokenTree::Group(group)) = fields.peek() {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        fields.next();
+                    }
+                }
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                if fields.peek().is_some() {
+                    return Err("Numeric can only be derived for structs with a single field");
+                }
+            }
+            _ => inner.push(token),
+        }
+    }
+    if inner.is_empty() {
+        return Err("Numeric can only be derived for structs with a single field");
+    }
+
+    let inner = inner.into_iter().collect::<TokenStream>().to_string();
+    Ok((name.to_string(), inner))
+}
+
+/// Generate the delegating `Numeric` impl
+fn expand(name: &str, inner: &str) -> TokenStream {
+    let numeric = format!("<{inner} as ::lineic::Numeric>");
+    format!(
+        "
+        impl ::lineic::Numeric for {name} {{
+            const MAX: Self = Self({numeric}::MAX);
+            const ZERO: Self = Self({numeric}::ZERO);
+            const ONE: Self = Self({numeric}::ONE);
+
+            fn abs(self) -> Self {{
+                Self({numeric}::abs(self.0))
+            }}
+
+            fn clamp(self, min: Self, max: Self) -> Self {{
+                Self({numeric}::clamp(self.0, min.0, max.0))
+            }}
+
+            fn abs_diff(self, other: Self) -> Self {{
+                Self({numeric}::abs_diff(self.0, other.0))
+            }}
+
+            fn lerp(lo: Self, hi: Self, t: f64) -> Self {{
+                Self({numeric}::lerp(lo.0, hi.0, t))
+            }}
+
+            fn scale(self, factor: impl ::lineic::Numeric) -> ::core::option::Option<Self> {{
+                {numeric}::scale(self.0, factor).map(Self)
+            }}
+
+            fn checked_sub(self, other: Self) -> ::core::option::Option<Self> {{
+                {numeric}::checked_sub(self.0, other.0).map(Self)
+            }}
+
+            fn checked_add(self, other: Self) -> ::core::option::Option<Self> {{
+                {numeric}::checked_add(self.0, other.0).map(Self)
+            }}
+
+            fn checked_mul(self, other: Self) -> ::core::option::Option<Self> {{
+                {numeric}::checked_mul(self.0, other.0).map(Self)
+            }}
+
+            fn checked_div(self, other: Self) -> ::core::option::Option<Self> {{
+                {numeric}::checked_div(self.0, other.0).map(Self)
+            }}
+
+            fn from_usize(value: usize) -> ::core::option::Option<Self> {{
+                {numeric}::from_usize(value).map(Self)
+            }}
+
+            fn into_f64(self) -> f64 {{
+                {numeric}::into_f64(self.0)
+            }}
+
+            fn from_f64(value: f64) -> ::core::option::Option<Self> {{
+                {numeric}::from_f64(value).map(Self)
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
@@ -0,0 +1,112 @@
+//! Implementations of the `approx` crate's `AbsDiffEq` and `RelativeEq` traits.
+//!
+//! These delegate to the inherent `abs_diff_eq` and `relative_eq` methods, so values are compared as f64.
+//! The default tolerances match the ones `approx` uses for `f64`.
+use crate::{InterpolationBucket, Numeric, ReversibleRange};
+use ::approx::{AbsDiffEq, RelativeEq};
+
+#[cfg(not(feature = "no_std"))]
+use crate::LinearInterpolator;
+
+impl<S: Numeric> AbsDiffEq for ReversibleRange<S> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        Self::abs_diff_eq(self, other, epsilon)
+    }
+}
+
+impl<S: Numeric> RelativeEq for ReversibleRange<S> {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        Self::relative_eq(self, other, epsilon, max_relative)
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> AbsDiffEq for InterpolationBucket<N, S, T> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        Self::abs_diff_eq(self, other, epsilon)
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> RelativeEq for InterpolationBucket<N, S, T> {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        Self::relative_eq(self, other, epsilon, max_relative)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<const N: usize, S: Numeric, T: Numeric> AbsDiffEq for LinearInterpolator<'_, N, S, T> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        Self::abs_diff_eq(self, other, epsilon)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<const N: usize, S: Numeric, T: Numeric> RelativeEq for LinearInterpolator<'_, N, S, T> {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        Self::relative_eq(self, other, epsilon, max_relative)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+
+    #[test]
+    fn test_range_approx() {
+        let range = ReversibleRange::new(0.0, 0.1 + 0.2);
+        assert_abs_diff_eq!(range, ReversibleRange::new(0.0, 0.3));
+        assert_abs_diff_ne!(range, ReversibleRange::new(0.0, 0.31));
+        assert_abs_diff_eq!(range, ReversibleRange::new(0.0, 0.31), epsilon = 0.02);
+    }
+
+    #[test]
+    fn test_bucket_approx() {
+        let bucket = InterpolationBucket::new((0.0, 1.0), [0.1 + 0.2], [1e9]);
+        assert_relative_eq!(bucket, InterpolationBucket::new((0.0, 1.0), [0.3], [1e9]));
+        assert_relative_eq!(
+            bucket,
+            InterpolationBucket::new((0.0, 1.0), [0.3], [1e9 + 1.0]),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_interpolator_approx() {
+        let a = LinearInterpolator::new(0.0..=0.3, &[[0.0], [1.0]]);
+        let b = LinearInterpolator::new(0.0..=0.1 + 0.2, &[[0.0], [1.0]]);
+        assert_ne!(a, b);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+    }
+}
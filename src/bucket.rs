@@ -1,4 +1,20 @@
-use crate::{number::Numeric, ReversibleRange};
+use crate::{
+    number::{relative_eq, saturating_offset, saturating_scale, Numeric},
+    Easing, ReversibleRange,
+};
+use core::mem::MaybeUninit;
+
+/// Controls how a single channel moves between its 2 values within a bucket.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum ChannelMode {
+    /// The channel blends smoothly between its values
+    #[default]
+    Continuous,
+
+    /// The channel holds its starting value until 50% of the range, then snaps to its ending value.  
+    /// Useful for discrete values such as flags or indices.
+    Step,
+}
 
 /// A value set for interpolation.  
 /// Interpolates between 2 sets of values based on a range.
@@ -41,92 +57,309 @@ impl<const N: usize, S: Numeric, T: Numeric> InterpolationBucket<N, S, T> {
         }
     }
 
-    /// Create a new interpolation bucket.  
-    /// - `range` is the range of values that this bucket interpolates between.
-    /// - `values_lo` is the set of values to interpolate from.
-    /// - `values_hi` is the set of values to interpolate to.
-    ///
-    /// This will enable the bucket to smoothly interpolate from lo to hi for T values in the range.  
-    /// Values < range min will be clamped to lo.  
-    /// Values > range max will be clamped to hi.
-    pub const fn from_tuple(range: (S, S), values_lo: [T; N], values_hi: [T; N]) -> Self {
-        let range = ReversibleRange::new(range.0, range.1);
-        Self {
-            range,
-            values_lo,
-            values_hi,
-        }
-    }
-
     /// Get the range of values that this bucket interpolates between.
-    pub fn range(&self) -> &ReversibleRange<S> {
+    pub const fn range(&self) -> &ReversibleRange<S> {
         &self.range
     }
 
+    /// Replace the range of values that this bucket interpolates between.  
+    /// When editing the buckets of an interpolator, use [`crate::LinearInterpolator::validate`] to check they still line up.
+    pub fn set_range(&mut self, range: impl Into<ReversibleRange<S>>) {
+        self.range = range.into();
+    }
+
     /// Get the start value of the range.
     pub fn start(&self) -> S {
-        self.range.start
+        self.range.start.clone()
     }
 
     /// Get the end value of the range.
     pub fn end(&self) -> S {
-        self.range.end
+        self.range.end.clone()
+    }
+
+    /// Check if the bucket's range contains the given value.  
+    /// Shortcut for `bucket.range().contains(s)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(10.0..=0.0, [0.0], [1.0]);
+    /// assert!(bucket.contains(5.0));
+    /// assert!(!bucket.contains(-1.0));
+    /// ```
+    pub fn contains(&self, s: S) -> bool {
+        self.range.contains(s)
+    }
+
+    /// Get how far through this bucket's range `s` is, from `0.0` at the start to `1.0` at the end.  
+    /// Values outside of the range are clamped, and reversed ranges still report `0.0` at their start.  
+    /// Empty ranges always report `0.0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(10.0..=0.0, [0.0], [1.0]);
+    /// assert_eq!(bucket.progress(10.0), 0.0);
+    /// assert_eq!(bucket.progress(7.5), 0.25);
+    /// assert_eq!(bucket.progress(-5.0), 1.0);
+    /// ```
+    pub fn progress(&self, s: S) -> f64 {
+        self.range.inverse_lerp(s)
     }
 
     /// Get the set of values to interpolate from.
-    pub fn values_lo(&self) -> &[T; N] {
+    pub const fn values_lo(&self) -> &[T; N] {
         &self.values_lo
     }
 
     /// Get the set of values to interpolate to.
-    pub fn values_hi(&self) -> &[T; N] {
+    pub const fn values_hi(&self) -> &[T; N] {
         &self.values_hi
     }
 
+    /// Get a mutable reference to the set of values to interpolate from.
+    pub fn values_lo_mut(&mut self) -> &mut [T; N] {
+        &mut self.values_lo
+    }
+
+    /// Get a mutable reference to the set of values to interpolate to.
+    pub fn values_hi_mut(&mut self) -> &mut [T; N] {
+        &mut self.values_hi
+    }
+
+    /// Replace the set of values to interpolate from.
+    pub fn set_values_lo(&mut self, values: [T; N]) {
+        self.values_lo = values;
+    }
+
+    /// Replace the set of values to interpolate to.
+    pub fn set_values_hi(&mut self, values: [T; N]) {
+        self.values_hi = values;
+    }
+
     /// Interpolate between the 2 value sets of this bucket at the given `t` value.
     /// This will return a new set of values that are interpolated between `values_lo` and `values_hi` based on `t`'s position in the bucket's range.
+    ///
+    /// If the range is empty (`start == end`) there is no span to interpolate across, and `values_lo` is returned for all inputs.
     pub fn interpolate(&self, s: S) -> [T; N] {
-        let start: S = self.start();
-        let end = self.end();
-        let lo = &self.values_lo;
-        let hi = &self.values_hi;
+        let mut values = [T::ZERO; N];
+        self.interpolate_into(s, &mut values);
+        values
+    }
+
+    /// Interpolate between the 2 value sets of this bucket at the given `t` value, writing the result into `out`.  
+    /// Produces the same values as [`Self::interpolate`], without returning them by value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=10.0, [0.0, 100.0], [100.0, 0.0]);
+    /// let mut out = [0.0; 2];
+    /// bucket.interpolate_into(2.5, &mut out);
+    /// assert_eq!(out, [25.0, 75.0]);
+    /// ```
+    pub fn interpolate_into(&self, s: S, out: &mut [T; N]) {
+        let rel_percent = self.progress(s);
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.lerp_channel(i, rel_percent);
+        }
+    }
+
+    /// Interpolate between the 2 value sets of this bucket at the given `t` value, using a mode per channel.  
+    /// Channels set to [`ChannelMode::Continuous`] are blended as in [`Self::interpolate`].  
+    /// Channels set to [`ChannelMode::Step`] snap to `values_lo` below 50% of the range, and to `values_hi` at or above it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{ChannelMode, InterpolationBucket};
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=10.0, [0.0, 0.0], [10.0, 1.0]);
+    /// let modes = [ChannelMode::Continuous, ChannelMode::Step];
+    ///
+    /// assert_eq!(bucket.interpolate_with_modes(4.0, &modes), [4.0, 0.0]);
+    /// assert_eq!(bucket.interpolate_with_modes(5.0, &modes), [5.0, 1.0]);
+    /// ```
+    pub fn interpolate_with_modes(&self, s: S, modes: &[ChannelMode; N]) -> [T; N] {
+        self.interpolate_progress_with_modes(self.progress(s), modes)
+    }
+
+    /// Interpolate at a progress through the bucket, from `0.0` at the start of its range to `1.0` at the end
+    #[cfg(not(feature = "no_std"))]
+    pub(crate) fn interpolate_progress(&self, rel_percent: f64) -> [T; N] {
+        core::array::from_fn(|i| self.lerp_channel(i, rel_percent))
+    }
+
+    /// Interpolate at a progress through the bucket, using a mode per channel; see [`Self::interpolate_with_modes`]
+    pub(crate) fn interpolate_progress_with_modes(
+        &self,
+        rel_percent: f64,
+        modes: &[ChannelMode; N],
+    ) -> [T; N] {
+        core::array::from_fn(|i| match modes[i] {
+            ChannelMode::Continuous => self.lerp_channel(i, rel_percent),
+            ChannelMode::Step if rel_percent < 0.5 => self.values_lo[i].clone(),
+            ChannelMode::Step => self.values_hi[i].clone(),
+        })
+    }
+
+    /// Interpolate between the 2 value sets of this bucket at the given `t` value, reshaping the progress with `easing`.  
+    /// The eased progress is clamped to `0.0..=1.0`, so the values never overshoot `values_lo` or `values_hi`.  
+    /// Easings that [overshoot](Easing::overshoots), like [`crate::easing::BackOut`], are not clamped, so the values may briefly pass the stops;
+    /// integer types saturate at their bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{easing::EaseInOutQuad, InterpolationBucket};
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=10.0, [0.0], [100.0]);
+    /// assert_eq!(bucket.interpolate_with_easing(2.5, &EaseInOutQuad), [12.5]);
+    /// assert_eq!(bucket.interpolate_with_easing(5.0, &EaseInOutQuad), [50.0]);
+    /// ```
+    pub fn interpolate_with_easing(&self, s: S, easing: &impl Easing) -> [T; N] {
+        let rel_percent = eased_progress(easing, self.progress(s));
+        core::array::from_fn(|i| self.lerp_channel(i, rel_percent))
+    }
+
+    /// Interpolate between the 2 value sets of this bucket at the given `t` value, reshaping the progress of each channel
+    /// with its own easing. See [`Self::interpolate_with_easing`] for details.
+    ///
+    /// To mix the built-in easings, use [`crate::easing::EasingMode`].
+    pub fn interpolate_per_channel_easing<E: Easing>(&self, s: S, easings: &[E; N]) -> [T; N] {
+        let rel_percent = self.progress(s);
+        core::array::from_fn(|i| self.lerp_channel(i, eased_progress(&easings[i], rel_percent)))
+    }
+
+    /// Interpolate between the 2 value sets of this bucket at the given `t` value, with the midpoint of the blend moved to `bias`.  
+    /// This reproduces CSS gradient color hints: the progress is remapped by a power curve, so that at `bias` of the way
+    /// through the range the values are an even blend. A `bias` of `0.5` is the same as [`Self::interpolate`],
+    /// while lower values move the transition earlier, and higher values later.
+    ///
+    /// Returns None if `bias` is not strictly between `0.0` and `1.0`
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=10.0, [0.0], [100.0]);
+    /// assert_eq!(bucket.interpolate_biased(2.5, 0.25), Some([50.0]));
+    /// assert_eq!(bucket.interpolate_biased(5.0, 0.5), Some(bucket.interpolate(5.0)));
+    /// assert_eq!(bucket.interpolate_biased(5.0, 1.0), None);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn interpolate_biased(&self, s: S, bias: f64) -> Option<[T; N]> {
+        if bias <= 0.0 || bias >= 1.0 || bias.is_nan() {
+            return None;
+        }
 
-        let len = self.range.len();
-        let value = s.clamp(start, end);
-        let rel_value = value.abs_diff(start);
-        let rel_percent = rel_value.into_f64() / len.into_f64();
+        let exponent = 0.5f64.ln() / bias.ln();
+        let rel_percent = self.progress(s).powf(exponent);
+        Some(core::array::from_fn(|i| self.lerp_channel(i, rel_percent)))
+    }
 
-        let mut values = *lo;
-        for (i, value) in values.iter_mut().enumerate() {
-            let diff = lo[i].abs_diff(hi[i]);
-            let adj = diff.scale(rel_percent).unwrap_or(T::MAX);
+    /// Blend a single channel from `values_lo` to `values_hi` by `rel_percent`
+    fn lerp_channel(&self, i: usize, rel_percent: f64) -> T {
+        T::lerp(
+            self.values_lo[i].clone(),
+            self.values_hi[i].clone(),
+            rel_percent,
+        )
+    }
 
-            *value = if lo[i] > hi[i] {
-                lo[i].checked_sub(adj).unwrap_or(T::ZERO)
+    /// Interpolate between the 2 value sets of this bucket, wrapping the channels where `wrap[i]` is true.  
+    /// Wrapped channels take the shortest path between their values, through the modulus `T::MAX + 1`,
+    /// instead of saturating at `T::ZERO` and `T::MAX`; the channel-level analogue of hue interpolation.
+    ///
+    /// Wrapped channels are treated as cyclic over `0..=T::MAX`, so this is intended for unsigned integer types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=10.0, [250u8, 250], [10, 10]);
+    /// let wrap = [true, false];
+    ///
+    /// // The wrapped channel goes up through 255 to 10, while the other falls to 10
+    /// assert_eq!(bucket.interpolate_wrapping_channels(5.0, &wrap), [2, 130]);
+    /// ```
+    pub fn interpolate_wrapping_channels(&self, s: S, wrap: &[bool; N]) -> [T; N] {
+        let rel_percent = self.progress(s);
+        core::array::from_fn(|i| {
+            if wrap[i] {
+                self.lerp_channel_wrapping(i, rel_percent)
             } else {
-                lo[i].checked_add(adj).unwrap_or(T::MAX)
-            };
+                self.lerp_channel(i, rel_percent)
+            }
+        })
+    }
+
+    /// Blend a single channel from `values_lo` to `values_hi` by `rel_percent`, along the shortest path modulo `T::MAX + 1`
+    pub(crate) fn lerp_channel_wrapping(&self, i: usize, rel_percent: f64) -> T {
+        let lo = self.values_lo[i].clone().into_f64();
+        let hi = self.values_hi[i].clone().into_f64();
+        let modulus = T::MAX.into_f64() + 1.0;
+
+        let mut diff = hi - lo;
+        if diff > modulus / 2.0 {
+            diff -= modulus;
+        } else if diff < -modulus / 2.0 {
+            diff += modulus;
         }
 
-        values
+        // The shortest path is at most half the modulus, so it wraps at most once
+        let mut value = lo + diff * rel_percent;
+        if value < 0.0 {
+            value += modulus;
+        } else if value >= modulus {
+            value -= modulus;
+        }
+
+        // Values between MAX and the modulus truncate down to MAX
+        T::from_f64(value).unwrap_or(T::MAX)
     }
 
     /// Attempt to retrieve the value within the bucket's range that would produce the given set of values.
     pub fn reverse_interpolate(&self, input: &[T; N]) -> Option<S> {
+        self.reverse_interpolate_masked(input, &[true; N])
+    }
+
+    /// Attempt to retrieve the value within the bucket's range that would produce the given set of values.  
+    /// Only channels where `mask[i]` is true are considered; the others are ignored entirely.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=10.0, [0, 0, 0, 255], [100, 100, 100, 255]);
+    ///
+    /// // Alpha does not match any point in the bucket, but is ignored
+    /// let mask = [true, true, true, false];
+    /// assert_eq!(bucket.reverse_interpolate_masked(&[50, 50, 50, 128], &mask), Some(5.0));
+    /// assert_eq!(bucket.reverse_interpolate(&[50, 50, 50, 128]), None);
+    /// ```
+    pub fn reverse_interpolate_masked(&self, input: &[T; N], mask: &[bool; N]) -> Option<S> {
         const DIFF_FLOOR: f64 = 1e-6; // Percentage difference below which values are considered equal
 
         let start = self.start();
         let end = self.end();
-        let len = self.end().abs_diff(start);
+        let len = end.clone().abs_diff(start.clone());
 
         let mut rel_percent = None;
         for (i, input) in input.iter().enumerate() {
-            if *input != input.clamp(self.values_lo[i], self.values_hi[i]) {
+            if !mask[i] {
+                continue; // Ignored channel
+            }
+
+            let (lo, hi) = (&self.values_lo[i], &self.values_hi[i]);
+            if *input != input.clone().clamp(lo.clone(), hi.clone()) {
                 return None; // Out of bounds
             }
 
-            let diff = self.values_lo[i].abs_diff(self.values_hi[i]).into_f64();
-            let diff2 = self.values_lo[i].abs_diff(*input).into_f64();
+            let diff = lo.clone().abs_diff(hi.clone()).into_f64();
+            let diff2 = lo.clone().abs_diff(input.clone()).into_f64();
             let min = diff.min(diff2);
             let max = diff.max(diff2);
             let percent = min / max;
@@ -146,7 +379,7 @@ impl<const N: usize, S: Numeric, T: Numeric> InterpolationBucket<N, S, T> {
 
         let mut rel_percent = rel_percent?;
 
-        if self.start() > self.end() {
+        if start > end {
             rel_percent = 1.0 - rel_percent;
         }
 
@@ -156,12 +389,332 @@ impl<const N: usize, S: Numeric, T: Numeric> InterpolationBucket<N, S, T> {
             end.checked_add(len.scale(rel_percent)?)
         }
     }
+
+    /// Estimate how far through this bucket `input` lies, tolerating noise, as `(progress, confidence)`.  
+    /// Each channel that changes across the bucket gives its own progress; the estimate is their mean, clamped to `0.0..=1.0`,
+    /// and the confidence is `1.0` minus the furthest any channel is from it, floored at `0.0`.  
+    /// Channels that stay constant across the bucket give no progress, so if `input` differs from one the confidence is `0.0`.
+    ///
+    /// Returns None if no channel changes across the bucket, so there is nothing to measure.
+    #[cfg(not(feature = "no_std"))]
+    pub(crate) fn reverse_interpolate_scored(&self, input: &[T; N]) -> Option<(f64, f64)> {
+        let constant_mismatch = input
+            .iter()
+            .zip(&self.values_lo)
+            .zip(&self.values_hi)
+            .any(|((input, lo), hi)| lo == hi && input != lo);
+
+        let percents = input
+            .iter()
+            .zip(&self.values_lo)
+            .zip(&self.values_hi)
+            .filter_map(|((input, lo), hi)| {
+                let lo = lo.clone().into_f64();
+                let diff = hi.clone().into_f64() - lo;
+                (diff != 0.0).then(|| (input.clone().into_f64() - lo) / diff)
+            });
+
+        let (count, sum) = percents
+            .clone()
+            .fold((0.0, 0.0), |(n, sum), p| (n + 1.0, sum + p));
+        if count == 0.0 {
+            return None;
+        }
+
+        let progress = (sum / count).clamp(0.0, 1.0);
+        if constant_mismatch {
+            return Some((progress, 0.0));
+        }
+
+        let spread = percents.fold(0.0, |spread: f64, p| spread.max((p - progress).abs()));
+        Some((progress, (1.0 - spread).max(0.0)))
+    }
+
+    /// Check if this bucket's range and values are all within `epsilon` of the other bucket's.  
+    /// Values are compared as f64; see [`Self::relative_eq`] for a tolerance that scales with magnitude.
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.relative_eq(other, epsilon, 0.0)
+    }
+
+    /// Check if this bucket's range and values are all approximately equal to the other bucket's.  
+    /// Values are equal if they differ by at most `epsilon`, or by at most `max_relative` times the larger of their magnitudes.
+    pub fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        let values_eq = |a: &[T; N], b: &[T; N]| {
+            a.iter()
+                .zip(b)
+                .all(|(a, b)| relative_eq(a, b, epsilon, max_relative))
+        };
+
+        self.range.relative_eq(&other.range, epsilon, max_relative)
+            && values_eq(&self.values_lo, &other.values_lo)
+            && values_eq(&self.values_hi, &other.values_hi)
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> InterpolationBucket<N, S, T> {
+    /// Write the buckets of an interpolator over the given range and value sets into a caller-provided buffer.  
+    /// The range is divided into equal segments, exactly as [`crate::LinearInterpolator::new`] does.
+    ///
+    /// This allows interpolators to be built without allocating, for example in `no_std` environments;  
+    /// the returned slice can be interpolated across directly, or passed to [`crate::LinearInterpolator::new_from_raw`].
+    ///
+    /// `buf` needs room for one bucket per pair of consecutive value sets, or a single bucket if there are fewer than 2.  
+    /// Any extra space is left untouched.
+    ///
+    /// Returns None if `buf` is too small, or if the number of value sets is too large to be represented by type S.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use lineic::{InterpolationBucket, LinearInterpolator};
+    ///
+    /// let value_sets = [[0.0], [10.0], [0.0]];
+    /// let mut buf = [MaybeUninit::uninit(), MaybeUninit::uninit()];
+    ///
+    /// let buckets = InterpolationBucket::fill_buckets(0.0..=100.0, &value_sets, &mut buf).unwrap();
+    /// let interpolator = LinearInterpolator::new_from_raw(buckets);
+    /// assert_eq!(interpolator, LinearInterpolator::new(0.0..=100.0, &value_sets));
+    /// ```
+    pub fn fill_buckets<'b>(
+        range: impl Into<ReversibleRange<S>>,
+        value_sets: &[[T; N]],
+        buf: &'b mut [MaybeUninit<Self>],
+    ) -> Option<&'b [Self]> {
+        let range = range.into();
+        let count = value_sets.len().saturating_sub(1).max(1);
+        let buf = buf.get_mut(..count)?;
+
+        match value_sets {
+            [] => {
+                buf[0].write(Self::new(range, [T::ZERO; N], [T::ZERO; N]));
+            }
+
+            [values] => {
+                buf[0].write(Self::new(range, values.clone(), values.clone()));
+            }
+
+            _ => {
+                let ranges = range.split(count)?;
+                for ((slot, range), v) in buf.iter_mut().zip(ranges).zip(value_sets.windows(2)) {
+                    slot.write(Self::new(range, v[0].clone(), v[1].clone()));
+                }
+            }
+        }
+
+        // SAFETY: All `count` elements of `buf` were initialized above,
+        // and `MaybeUninit<Self>` has the same layout as `Self`
+        Some(unsafe { &*(core::ptr::from_ref(buf) as *const [Self]) })
+    }
+}
+
+impl<const N: usize, S: Numeric + Copy, T: Numeric + Copy> InterpolationBucket<N, S, T> {
+    /// Create a new interpolation bucket.  
+    /// - `range` is the range of values that this bucket interpolates between.
+    /// - `values_lo` is the set of values to interpolate from.
+    /// - `values_hi` is the set of values to interpolate to.
+    ///
+    /// This will enable the bucket to smoothly interpolate from lo to hi for T values in the range.  
+    /// Values < range min will be clamped to lo.  
+    /// Values > range max will be clamped to hi.
+    ///
+    /// Unlike [`Self::new`], this can be used in const contexts, so it is only available for `Copy` types.
+    pub const fn from_tuple(range: (S, S), values_lo: [T; N], values_hi: [T; N]) -> Self {
+        let range = ReversibleRange::new(range.0, range.1);
+        Self {
+            range,
+            values_lo,
+            values_hi,
+        }
+    }
+}
+
+impl<S: Numeric, T: Numeric> InterpolationBucket<2, S, T> {
+    /// Interpolate between the 2 sets of values, returning the result as a tuple.  
+    /// Useful for 2D coordinates.
+    pub fn interpolate_tuple(&self, s: S) -> (T, T) {
+        let [a, b] = self.interpolate(s);
+        (a, b)
+    }
+}
+
+impl<S: Numeric, T: Numeric> InterpolationBucket<3, S, T> {
+    /// Interpolate between the 2 sets of values, returning the result as a tuple.  
+    /// Useful for 3D coordinates or RGB colors.
+    pub fn interpolate_tuple(&self, s: S) -> (T, T, T) {
+        let [a, b, c] = self.interpolate(s);
+        (a, b, c)
+    }
+}
+
+/// Implements const interpolation for a bucket using the same float type for its range and values.  
+/// Mirrors the arithmetic of the generic [`InterpolationBucket::interpolate`], so results are identical.
+macro_rules! impl_const_interpolate {
+    ($t:ty) => {
+        impl<const N: usize> InterpolationBucket<N, $t, $t> {
+            /// Interpolate between the 2 value sets of this bucket at the given `s` value, in a const context.
+            /// Produces the same result as [`Self::interpolate`].
+            ///
+            /// Const interpolation is only available for buckets of `f32` ranges and values, or `f64` ranges and values.
+            ///
+            /// # Example
+            /// ```rust
+            /// use lineic::InterpolationBucket;
+            ///
+            /// const BUCKET: InterpolationBucket<1, f32, f32> = InterpolationBucket::from_tuple((0.0, 10.0), [0.0], [255.0]);
+            /// const MIDPOINT: [f32; 1] = BUCKET.interpolate_const(5.0);
+            /// assert_eq!(MIDPOINT, [127.5]);
+            /// ```
+            #[must_use]
+            pub const fn interpolate_const(&self, s: $t) -> [$t; N] {
+                let rel_percent = self.progress_const(s);
+
+                let mut values = self.values_lo;
+                let mut i = 0;
+                while i < N {
+                    values[i] = self.lerp_channel_const(i, rel_percent);
+                    i += 1;
+                }
+
+                values
+            }
+
+            /// Const equivalent of [`Self::progress`]
+            pub(crate) const fn progress_const(&self, s: $t) -> f64 {
+                let start = self.range.start;
+                let end = self.range.end;
+                let (min, max) = if start > end {
+                    (end, start)
+                } else {
+                    (start, end)
+                };
+
+                let value = if s < min {
+                    min
+                } else if s > max {
+                    max
+                } else {
+                    s
+                };
+                let rel_value = <$t>::abs(value - start);
+                let len = <$t>::abs(start - end);
+                if len == 0.0 {
+                    return 0.0;
+                }
+                rel_value as f64 / len as f64
+            }
+
+            /// Const equivalent of [`Self::lerp_channel`]
+            #[allow(clippy::cast_possible_truncation)]
+            pub(crate) const fn lerp_channel_const(&self, i: usize, rel_percent: f64) -> $t {
+                let lo = self.values_lo[i];
+                let hi = self.values_hi[i];
+
+                let diff = <$t>::abs(lo - hi);
+                let adj = (diff as f64 * rel_percent) as $t;
+
+                if lo > hi {
+                    lo - adj
+                } else {
+                    lo + adj
+                }
+            }
+        }
+    };
+}
+
+impl_const_interpolate!(f32);
+impl_const_interpolate!(f64);
+
+/// Multiplies both value sets by a factor, keeping the range.  
+/// Each value is scaled in f64; results that cannot be represented by type T saturate to its maximum or lowest value
+/// (`ZERO` for unsigned types, `-MAX` for signed ones), and integer results truncate towards zero.
+///
+/// ```rust
+/// use lineic::InterpolationBucket;
+///
+/// let bucket = InterpolationBucket::new(0.0..=1.0, [255u8, 10], [0, 200]) * 2.0;
+/// assert_eq!(bucket.values_lo(), &[255, 20]);
+/// assert_eq!(bucket.values_hi(), &[0, 255]);
+/// ```
+impl<const N: usize, S: Numeric, T: Numeric> core::ops::Mul<f64> for InterpolationBucket<N, S, T> {
+    type Output = Self;
+
+    fn mul(mut self, factor: f64) -> Self {
+        for value in self.values_lo.iter_mut().chain(&mut self.values_hi) {
+            *value = saturating_scale(value.clone(), factor);
+        }
+        self
+    }
+}
+
+/// Adds a delta, per channel, to both value sets, keeping the range.  
+/// Results that overflow type T saturate to its maximum or lowest value, except for types that opt in to wrapping.
+///
+/// ```rust
+/// use lineic::InterpolationBucket;
+///
+/// let bucket = InterpolationBucket::new(0.0..=1.0, [0u8, 250], [100, 0]) + [16, 16];
+/// assert_eq!(bucket.values_lo(), &[16, 255]);
+/// assert_eq!(bucket.values_hi(), &[116, 16]);
+/// ```
+impl<const N: usize, S: Numeric, T: Numeric> core::ops::Add<[T; N]>
+    for InterpolationBucket<N, S, T>
+{
+    type Output = Self;
+
+    fn add(mut self, delta: [T; N]) -> Self {
+        for values in [&mut self.values_lo, &mut self.values_hi] {
+            for (value, delta) in values.iter_mut().zip(&delta) {
+                *value = saturating_offset(value.clone(), delta.clone());
+            }
+        }
+        self
+    }
+}
+
+/// Ease the progress through a bucket, clamping it to `0.0..=1.0` unless the easing overshoots
+fn eased_progress<E: Easing + ?Sized>(easing: &E, t: f64) -> f64 {
+    let eased = easing.ease(t);
+    if easing.overshoots() {
+        eased
+    } else {
+        eased.clamp(0.0, 1.0)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_overshooting_easing() {
+        use crate::easing::{BackIn, BackOut};
+
+        // Overshoots pass the stops, saturating at the bounds of integer types
+        let bucket = InterpolationBucket::new((0u8, 10), [0u8, 100], [100, 250]);
+        assert_eq!(bucket.interpolate_with_easing(8, &BackOut), [104, u8::MAX]);
+        assert_eq!(bucket.interpolate_with_easing(2, &BackIn), [0, 94]);
+
+        // Non-overshooting closures are still clamped
+        let wild = |t: f64| t * 3.0;
+        assert_eq!(bucket.interpolate_with_easing(8, &wild), [100, 250]);
+    }
+
+    #[test]
+    fn test_setters() {
+        let mut bucket = InterpolationBucket::new((0u8, 10), [0u8, 0], [100, 100]);
+        bucket.set_values_lo([50, 100]);
+        bucket.set_values_hi([150, 0]);
+        assert_eq!(bucket.interpolate(5), [100, 50]);
+
+        bucket.set_range((20, 0));
+        assert_eq!(bucket.range(), &ReversibleRange::new(20, 0));
+        assert_eq!(bucket.interpolate(15), [75, 75]);
+
+        bucket.values_lo_mut()[0] = 0;
+        assert_eq!(bucket.values_lo(), &[0, 100]);
+    }
+
     #[test]
     fn test_interpolation_bucket() {
         const RED: [u8; 3] = [255, 50, 50];
@@ -183,4 +736,190 @@ mod test {
             Some(0.6)
         );
     }
+
+    #[test]
+    fn test_reverse_interpolate_masked() {
+        let bucket = InterpolationBucket::new((10.0, 0.0), [0, 200, 9], [100, 0, 9]);
+        assert_eq!(
+            bucket.reverse_interpolate_masked(&[25, 150, 0], &[true, true, false]),
+            Some(7.5)
+        );
+        assert_eq!(
+            bucket.reverse_interpolate_masked(&[25, 0, 9], &[true, false, true]),
+            Some(7.5)
+        );
+
+        // Nothing to solve for
+        assert_eq!(
+            bucket.reverse_interpolate_masked(&[25, 150, 0], &[false; 3]),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_interpolate_const() {
+        let bucket = InterpolationBucket::new((10.0f32, 0.0), [0.0, 100.0, 3.3], [255.0, 0.0, 3.3]);
+        for s in [-1.0, 0.0, 0.1, 3.7, 5.0, 9.99, 10.0, 11.0] {
+            assert_eq!(bucket.interpolate_const(s), bucket.interpolate(s));
+        }
+
+        let bucket = InterpolationBucket::new((0.0f64, 0.3), [0.1], [0.7]);
+        for s in [0.0, 0.1, 0.2, 0.3] {
+            assert_eq!(bucket.interpolate_const(s), bucket.interpolate(s));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_empty_range() {
+        let bucket = InterpolationBucket::new((5.0, 5.0), [0.0], [10.0]);
+        assert_eq!(bucket.interpolate(5.0), [0.0]);
+        assert_eq!(bucket.interpolate(0.0), [0.0]);
+        assert_eq!(bucket.interpolate(f64::MAX), [0.0]);
+        assert_eq!(bucket.progress(5.0), 0.0);
+        assert_eq!(bucket.interpolate_const(5.0), [0.0]);
+
+        let bucket = InterpolationBucket::new((5u8, 5), [0u8], [10]);
+        assert_eq!(bucket.interpolate(5), [0]);
+        assert_eq!(bucket.interpolate(6), [0]);
+    }
+
+    #[test]
+    fn test_interpolate_wrapping_channels() {
+        let bucket = InterpolationBucket::new((0u8, 100), [250u8, 10, 0, 100], [10, 250, 255, 200]);
+        let wrap = [true, true, true, true];
+
+        assert_eq!(
+            bucket.interpolate_wrapping_channels(0, &wrap),
+            [250, 10, 0, 100]
+        );
+        assert_eq!(
+            bucket.interpolate_wrapping_channels(25, &wrap),
+            [254, 6, 255, 125]
+        );
+        assert_eq!(
+            bucket.interpolate_wrapping_channels(50, &wrap),
+            [2, 2, 255, 150]
+        );
+        assert_eq!(
+            bucket.interpolate_wrapping_channels(100, &wrap),
+            [10, 250, 255, 200]
+        );
+
+        // Unwrapped channels behave as in `interpolate`
+        let wrap = [false, true, false, true];
+        let values = bucket.interpolate_wrapping_channels(50, &wrap);
+        let plain = bucket.interpolate(50);
+        assert_eq!([values[0], values[2]], [plain[0], plain[2]]);
+    }
+
+    #[test]
+    fn test_interpolate_into() {
+        let bucket = InterpolationBucket::new((100u8, 0), [0u16, 1000, 7], [1000, 0, 7]);
+        let mut out = [u16::MAX; 3];
+        for s in [0, 1, 33, 50, 99, 100, 200] {
+            bucket.interpolate_into(s, &mut out);
+            assert_eq!(out, bucket.interpolate(s));
+        }
+    }
+
+    #[test]
+    fn test_value_operators() {
+        let bucket = InterpolationBucket::new((0u8, 10), [-100i8, 0, 50], [100, 10, -50]);
+
+        let scaled = bucket.clone() * 1.5;
+        assert_eq!(scaled.range(), bucket.range());
+        assert_eq!(scaled.values_lo(), &[-127, 0, 75]);
+        assert_eq!(scaled.values_hi(), &[127, 15, -75]);
+        assert_eq!(scaled.interpolate(5)[1], 7);
+
+        let offset = bucket + [100, -10, 0];
+        assert_eq!(offset.values_lo(), &[0, -10, 50]);
+        assert_eq!(offset.values_hi(), &[127, 0, -50]);
+    }
+
+    #[test]
+    fn test_interpolate_tuple() {
+        let bucket = InterpolationBucket::new((0, 10), [0u8, 100], [100, 0]);
+        assert_eq!(bucket.interpolate_tuple(3), (30, 70));
+
+        let bucket = InterpolationBucket::new((0, 10), [0u8, 100, 50], [100, 0, 50]);
+        assert_eq!(bucket.interpolate_tuple(3), (30, 70, 50));
+    }
+
+    #[test]
+    fn test_interpolate_with_modes() {
+        let bucket = InterpolationBucket::new((10.0, 0.0), [0, 0, 100], [100, 4, 0]);
+        let modes = [
+            ChannelMode::Continuous,
+            ChannelMode::Step,
+            ChannelMode::Step,
+        ];
+
+        assert_eq!(bucket.interpolate_with_modes(10.0, &modes), [0, 0, 100]);
+        assert_eq!(bucket.interpolate_with_modes(6.0, &modes), [40, 0, 100]);
+        assert_eq!(bucket.interpolate_with_modes(5.0, &modes), [50, 4, 0]);
+        assert_eq!(bucket.interpolate_with_modes(0.0, &modes), [100, 4, 0]);
+
+        let continuous = [ChannelMode::Continuous; 3];
+        assert_eq!(
+            bucket.interpolate_with_modes(3.0, &continuous),
+            bucket.interpolate(3.0)
+        );
+    }
+
+    #[test]
+    fn test_fill_buckets() {
+        let mut buf = [
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+        ];
+
+        let value_sets = [[0u8], [10], [20], [30]];
+        let buckets = InterpolationBucket::fill_buckets((10u8, 0), &value_sets, &mut buf).unwrap();
+        assert_eq!(
+            buckets,
+            [
+                InterpolationBucket::new((10, 7), [0], [10]),
+                InterpolationBucket::new((7, 4), [10], [20]),
+                InterpolationBucket::new((4, 0), [20], [30]),
+            ]
+        );
+
+        let buckets = InterpolationBucket::fill_buckets((0u8, 10), &[[5u8]], &mut buf).unwrap();
+        assert_eq!(buckets, [InterpolationBucket::new((0, 10), [5], [5])]);
+
+        let buckets =
+            InterpolationBucket::<1, u8, u8>::fill_buckets((0, 10), &[], &mut buf).unwrap();
+        assert_eq!(buckets, [InterpolationBucket::new((0, 10), [0], [0])]);
+
+        // Not enough room
+        assert!(InterpolationBucket::fill_buckets((0u8, 10), &value_sets, &mut buf[..2]).is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_interpolate_biased() {
+        let bucket = InterpolationBucket::new((100u8, 0), [0u8, 200], [200, 0]);
+        assert_eq!(bucket.interpolate_biased(100, 0.2), Some([0, 200]));
+        assert_eq!(bucket.interpolate_biased(80, 0.2), Some([100, 100]));
+        assert_eq!(bucket.interpolate_biased(20, 0.8), Some([100, 100]));
+        assert_eq!(
+            bucket.interpolate_biased(50, 0.5),
+            Some(bucket.interpolate(50))
+        );
+        assert_eq!(bucket.interpolate_biased(0, 0.2), Some([200, 0]));
+
+        // Earlier hints make the blend further along at any point, and later hints less so
+        let [early, _] = bucket.interpolate_biased(60, 0.3).unwrap();
+        let [late, _] = bucket.interpolate_biased(60, 0.7).unwrap();
+        assert!(early > 80 && late < 80);
+
+        assert!(bucket.interpolate_biased(50, 0.0).is_none());
+        assert!(bucket.interpolate_biased(50, -0.5).is_none());
+        assert!(bucket.interpolate_biased(50, f64::NAN).is_none());
+    }
 }
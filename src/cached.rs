@@ -0,0 +1,106 @@
+use crate::{Interpolate, InterpolationBucket, LinearInterpolator, Numeric};
+use std::cell::Cell;
+
+/// A wrapper around a [`LinearInterpolator`] that remembers the last bucket used.
+///
+/// Each call to [`CachedInterpolator::interpolate`] first checks the bucket used by the previous call,
+/// only falling back to a binary search if the value falls outside of it.  
+/// For temporally coherent inputs, such as consecutive animation frames, this makes most lookups O(1).
+///
+/// # Example
+/// ```rust
+/// use lineic::{CachedInterpolator, LinearInterpolator};
+///
+/// let interpolator = LinearInterpolator::new(0.0..=100.0, &[[0.0], [10.0], [0.0], [10.0]]);
+/// let cached = CachedInterpolator::new(interpolator);
+///
+/// for frame in 0..=100 {
+///     let s = f64::from(frame);
+///     assert_eq!(cached.interpolate(s), cached.interpolator().interpolate(s));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachedInterpolator<'a, const N: usize, S: Numeric, T: Numeric> {
+    interpolator: LinearInterpolator<'a, N, S, T>,
+    last_bucket: Cell<usize>,
+}
+impl<'a, const N: usize, S: Numeric, T: Numeric> CachedInterpolator<'a, N, S, T> {
+    /// Wrap an interpolator, caching the last bucket used for interpolation.
+    pub fn new(interpolator: LinearInterpolator<'a, N, S, T>) -> Self {
+        Self {
+            interpolator,
+            last_bucket: Cell::new(0),
+        }
+    }
+
+    /// Get the wrapped interpolator.
+    pub fn interpolator(&self) -> &LinearInterpolator<'a, N, S, T> {
+        &self.interpolator
+    }
+
+    /// Unwrap the interpolator, discarding the cache.
+    pub fn into_inner(self) -> LinearInterpolator<'a, N, S, T> {
+        self.interpolator
+    }
+
+    /// Returns the bucket that contains the given value.  
+    /// Checks the last bucket used before falling back to a binary search.
+    pub fn get_bucket(&self, s: S) -> &InterpolationBucket<N, S, T> {
+        let buckets = self.interpolator.buckets();
+        if let Some(bucket) = buckets.get(self.last_bucket.get()) {
+            if bucket.contains(s.clone()) {
+                return bucket;
+            }
+        }
+
+        let index = self.interpolator.bucket_index(s);
+        self.last_bucket.set(index);
+        &buckets[index]
+    }
+
+    /// Interpolate between the value sets based on the given value.  
+    /// Produces the same result as [`LinearInterpolator::interpolate`].
+    pub fn interpolate(&self, s: S) -> [T; N] {
+        self.interpolator
+            .interpolate_in(self.get_bucket(s.clone()), s)
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> Interpolate<N, S, T>
+    for CachedInterpolator<'_, N, S, T>
+{
+    fn interpolate(&self, s: S) -> [T; N] {
+        CachedInterpolator::interpolate(self, s)
+    }
+}
+
+impl<'a, const N: usize, S: Numeric, T: Numeric> From<LinearInterpolator<'a, N, S, T>>
+    for CachedInterpolator<'a, N, S, T>
+{
+    fn from(interpolator: LinearInterpolator<'a, N, S, T>) -> Self {
+        Self::new(interpolator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cached_interpolate() {
+        let value_sets: Vec<_> = (0..20).map(|i| [i * i, 400 - i * 10]).collect();
+        let interpolator = LinearInterpolator::new((1000u32, 0), &value_sets);
+        let cached = CachedInterpolator::new(interpolator.clone());
+
+        // Coherent sweeps in both directions, and random jumps
+        // Values on a bucket boundary may resolve to either neighbour, which agree on the result
+        let inputs = (0..=1000).chain((0..=1000).rev()).chain([999, 3, 500, 0]);
+        for s in inputs {
+            assert!(cached.get_bucket(s).contains(s));
+            assert_eq!(cached.interpolate(s), interpolator.interpolate(s));
+        }
+
+        // Out of range values behave like the uncached interpolator
+        assert_eq!(cached.interpolate(1001), interpolator.interpolate(1001));
+    }
+}
@@ -0,0 +1,246 @@
+//! HSL and HSV interpolation for RGB value sets.
+//!
+//! Channel values are treated as RGB components in the range `0..=255`, regardless of type.
+use crate::{InterpolationBucket, Numeric};
+
+#[cfg(not(feature = "no_std"))]
+use crate::LinearInterpolator;
+
+/// Upper bound of an RGB component
+const RGB_MAX: f64 = 255.0;
+
+impl<S: Numeric, T: Numeric> InterpolationBucket<3, S, T> {
+    /// Interpolate between the 2 RGB value sets of this bucket in HSL color space.  
+    /// Hue is blended along the shortest arc around the color wheel; saturation and lightness are blended linearly.
+    ///
+    /// Values are treated as RGB components in `0..=255`, and the result is converted back to RGB in the same scale.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// // Red to green passes through yellow, instead of a dull brown
+    /// let bucket = InterpolationBucket::new(0.0..=1.0, [255u8, 0, 0], [0, 255, 0]);
+    /// assert_eq!(bucket.interpolate_hsl(0.5), [255.0, 255.0, 0.0]);
+    /// ```
+    pub fn interpolate_hsl(&self, s: S) -> [f32; 3] {
+        let t = self.progress(s);
+        let lo = rgb_to_hsl(normalize(self.values_lo()));
+        let hi = rgb_to_hsl(normalize(self.values_hi()));
+        denormalize(hsl_to_rgb(blend_hue_space(lo, hi, t)))
+    }
+
+    /// Interpolate between the 2 RGB value sets of this bucket in HSV color space.  
+    /// Hue is blended along the shortest arc around the color wheel; saturation and value are blended linearly.
+    ///
+    /// Values are treated as RGB components in `0..=255`, and the result is converted back to RGB in the same scale.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=1.0, [255u8, 0, 0], [0, 0, 255]);
+    /// assert_eq!(bucket.interpolate_hsv(0.5), [255.0, 0.0, 255.0]);
+    /// ```
+    pub fn interpolate_hsv(&self, s: S) -> [f32; 3] {
+        let t = self.progress(s);
+        let lo = rgb_to_hsv(normalize(self.values_lo()));
+        let hi = rgb_to_hsv(normalize(self.values_hi()));
+        denormalize(hsv_to_rgb(blend_hue_space(lo, hi, t)))
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<S: Numeric, T: Numeric> LinearInterpolator<'_, 3, S, T> {
+    /// Interpolate between the RGB value sets in HSL color space, based on the given value.  
+    /// See [`InterpolationBucket::interpolate_hsl`] for details.
+    ///
+    /// This operates directly on the stop values; channel modes and the output clamp are not applied.
+    pub fn interpolate_hsl(&self, s: S) -> [f32; 3] {
+        self.get_bucket(s.clone()).interpolate_hsl(s)
+    }
+
+    /// Interpolate between the RGB value sets in HSV color space, based on the given value.  
+    /// See [`InterpolationBucket::interpolate_hsv`] for details.
+    ///
+    /// This operates directly on the stop values; channel modes and the output clamp are not applied.
+    pub fn interpolate_hsv(&self, s: S) -> [f32; 3] {
+        self.get_bucket(s.clone()).interpolate_hsv(s)
+    }
+}
+
+/// Convert RGB values in `0..=255` to `0.0..=1.0`
+fn normalize<T: Numeric>(rgb: &[T; 3]) -> [f64; 3] {
+    rgb.clone()
+        .map(|c| c.into_f64().clamp(0.0, RGB_MAX) / RGB_MAX)
+}
+
+/// Convert RGB values in `0.0..=1.0` to `0..=255`
+#[allow(clippy::cast_possible_truncation)]
+fn denormalize(rgb: [f64; 3]) -> [f32; 3] {
+    rgb.map(|c| (c * RGB_MAX) as f32)
+}
+
+/// Blend 2 colors in a hue-based color space (hue in degrees, then 2 linear components)  
+/// Hue takes the shortest path around the wheel. An achromatic color has no meaningful hue, so the other color's hue is used.
+fn blend_hue_space(lo: [f64; 3], hi: [f64; 3], t: f64) -> [f64; 3] {
+    let (lo_hue, hi_hue) = match (lo[1] == 0.0, hi[1] == 0.0) {
+        (true, false) => (hi[0], hi[0]),
+        (false, true) => (lo[0], lo[0]),
+        _ => (lo[0], hi[0]),
+    };
+
+    let mut delta = hi_hue - lo_hue;
+    if delta > 180.0 {
+        delta -= 360.0;
+    } else if delta < -180.0 {
+        delta += 360.0;
+    }
+
+    let hue = (lo_hue + delta * t) % 360.0;
+    let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+
+    [
+        hue,
+        lo[1] + (hi[1] - lo[1]) * t,
+        lo[2] + (hi[2] - lo[2]) * t,
+    ]
+}
+
+/// Get the hue, in degrees, of an RGB color given its largest component and chroma
+#[allow(clippy::float_cmp)] // `max` is always exactly one of the components
+fn hue(rgb: [f64; 3], max: f64, chroma: f64) -> f64 {
+    let [r, g, b] = rgb;
+    if chroma == 0.0 {
+        return 0.0;
+    }
+
+    let hue = if max == r {
+        ((g - b) / chroma) % 6.0
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    } * 60.0;
+
+    if hue < 0.0 {
+        hue + 360.0
+    } else {
+        hue
+    }
+}
+
+/// Convert a hue, chroma and offset back to RGB
+fn from_hue(hue: f64, chroma: f64, offset: f64) -> [f64; 3] {
+    let sector = hue / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let [r, g, b] = match sector {
+        _ if sector < 1.0 => [chroma, x, 0.0],
+        _ if sector < 2.0 => [x, chroma, 0.0],
+        _ if sector < 3.0 => [0.0, chroma, x],
+        _ if sector < 4.0 => [0.0, x, chroma],
+        _ if sector < 5.0 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    };
+
+    [r + offset, g + offset, b + offset]
+}
+
+/// Convert an RGB color (`0.0..=1.0`) to HSL (degrees, `0.0..=1.0`, `0.0..=1.0`)
+fn rgb_to_hsl(rgb: [f64; 3]) -> [f64; 3] {
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    let min = rgb[0].min(rgb[1]).min(rgb[2]);
+    let chroma = max - min;
+
+    let lightness = f64::midpoint(max, min);
+    let saturation = if chroma == 0.0 {
+        0.0
+    } else {
+        chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+
+    [hue(rgb, max, chroma), saturation, lightness]
+}
+
+/// Convert an HSL color (degrees, `0.0..=1.0`, `0.0..=1.0`) to RGB (`0.0..=1.0`)
+fn hsl_to_rgb(hsl: [f64; 3]) -> [f64; 3] {
+    let [hue, saturation, lightness] = hsl;
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    from_hue(hue, chroma, lightness - chroma / 2.0)
+}
+
+/// Convert an RGB color (`0.0..=1.0`) to HSV (degrees, `0.0..=1.0`, `0.0..=1.0`)
+pub(crate) fn rgb_to_hsv(rgb: [f64; 3]) -> [f64; 3] {
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    let min = rgb[0].min(rgb[1]).min(rgb[2]);
+    let chroma = max - min;
+
+    let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+    [hue(rgb, max, chroma), saturation, max]
+}
+
+/// Convert an HSV color (degrees, `0.0..=1.0`, `0.0..=1.0`) to RGB (`0.0..=1.0`)
+pub(crate) fn hsv_to_rgb(hsv: [f64; 3]) -> [f64; 3] {
+    let [hue, saturation, value] = hsv;
+    let chroma = value * saturation;
+    from_hue(hue, chroma, value - chroma)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-3, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for rgb in [
+            [0.2, 0.4, 0.6],
+            [1.0, 0.0, 0.5],
+            [0.5, 0.5, 0.5],
+            [0.9, 0.8, 0.1],
+        ] {
+            let hsl = hsl_to_rgb(rgb_to_hsl(rgb));
+            let hsv = hsv_to_rgb(rgb_to_hsv(rgb));
+            for i in 0..3 {
+                assert!((hsl[i] - rgb[i]).abs() < 1e-9);
+                assert!((hsv[i] - rgb[i]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_hsl() {
+        let bucket = InterpolationBucket::new((0.0, 1.0), [255u8, 0, 0], [0, 255, 0]);
+        assert_close(bucket.interpolate_hsl(0.0), [255.0, 0.0, 0.0]);
+        assert_close(bucket.interpolate_hsl(0.5), [255.0, 255.0, 0.0]);
+        assert_close(bucket.interpolate_hsl(1.0), [0.0, 255.0, 0.0]);
+
+        // Hue wraps through 0 instead of travelling the long way around
+        let bucket = InterpolationBucket::new((0.0, 1.0), [255.0, 0.0, 42.5], [255.0, 42.5, 0.0]);
+        assert_close(bucket.interpolate_hsl(0.5), [255.0, 0.0, 0.0]);
+
+        // Blending from grey keeps the hue of the other color
+        let bucket = InterpolationBucket::new((0.0, 1.0), [128u8, 128, 128], [0, 0, 255]);
+        let [r, g, b] = bucket.interpolate_hsl(0.5);
+        assert!((r - g).abs() < 1e-3 && b > r);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_interpolate_hsv() {
+        let interpolator = LinearInterpolator::new(
+            100.0..=0.0,
+            &[[255.0, 0.0, 0.0], [0.0, 0.0, 255.0], [0.0, 255.0, 0.0]],
+        );
+        assert_close(interpolator.interpolate_hsv(100.0), [255.0, 0.0, 0.0]);
+        assert_close(interpolator.interpolate_hsv(75.0), [255.0, 0.0, 255.0]);
+        assert_close(interpolator.interpolate_hsv(25.0), [0.0, 255.0, 255.0]);
+        assert_close(interpolator.interpolate_hsl(0.0), [0.0, 255.0, 0.0]);
+    }
+}
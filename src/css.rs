@@ -0,0 +1,496 @@
+//! Conversion between RGB(A) interpolators and CSS `linear-gradient` strings.
+//!
+//! Stop positions are written as percentages of the interpolator's range, so any range can be exported.  
+//! Parsed gradients are placed over `0.0..=100.0`, with each stop at its percentage.
+use crate::{parse::Parser, LinearInterpolator, Numeric, ParseError};
+
+impl<S: Numeric> LinearInterpolator<'_, 3, S, u8> {
+    /// Export this interpolator as a CSS `linear-gradient`, running left to right.  
+    /// Each stop is written as an `rgb()` color, at its position as a percentage of the range.
+    ///
+    /// Channel modes and the output clamp are not represented.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=150.0, &[[255u8, 0, 0], [255, 255, 0], [0, 128, 0]]);
+    /// assert_eq!(
+    ///     interpolator.to_css_gradient(),
+    ///     "linear-gradient(to right, rgb(255, 0, 0) 0%, rgb(255, 255, 0) 50%, rgb(0, 128, 0) 100%)"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_css_gradient(&self) -> String {
+        self.css_gradient(|[r, g, b]| format!("rgb({r}, {g}, {b})"))
+    }
+
+    /// Parse a CSS `linear-gradient` into an interpolator over `0.0..=100.0`, with each stop at its percentage.  
+    /// Colors may be given as `rgb()`, `rgba()` or `#hex`, and any alpha is discarded; see [`Self::to_css_gradient`] for the reverse.
+    ///
+    /// Stops without a position are spread evenly between their neighbours, and the first and last colors
+    /// are held out to 0% and 100% if their stops are inset, as in CSS.  
+    /// A leading direction or angle is skipped, since the interpolator has no direction. Named colors are not supported.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] with the line and column of the first syntax error,
+    /// or of a stop whose position cannot be represented by type S.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let gradient =
+    ///     LinearInterpolator::<3, f64, u8>::from_css_gradient("linear-gradient(90deg, #f00, rgb(0, 0, 255) 80%, #fff)").unwrap();
+    /// assert_eq!(gradient.interpolate(40.0), [128, 0, 127]);
+    /// assert_eq!(gradient.interpolate(90.0), [127, 127, 255]);
+    /// ```
+    pub fn from_css_gradient(input: &str) -> Result<Self, ParseError> {
+        let (positions, colors) = parse_css_gradient(input)?;
+        let value_sets: Vec<_> = colors.iter().map(|[r, g, b, _]| [*r, *g, *b]).collect();
+        Ok(Self::from_stops(&positions, &value_sets))
+    }
+}
+
+impl<S: Numeric> LinearInterpolator<'_, 4, S, u8> {
+    /// Export this interpolator as a CSS `linear-gradient`, running left to right.  
+    /// Each stop is written as an `rgba()` color, with alpha in `0..=1`, at its position as a percentage of the range.
+    ///
+    /// Channel modes and the output clamp are not represented.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &[[255u8, 0, 0, 255], [0, 0, 255, 0]]);
+    /// assert_eq!(
+    ///     interpolator.to_css_gradient(),
+    ///     "linear-gradient(to right, rgba(255, 0, 0, 1) 0%, rgba(0, 0, 255, 0) 100%)"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_css_gradient(&self) -> String {
+        self.css_gradient(|[r, g, b, a]| {
+            let alpha = round_to(f64::from(*a) / 255.0, 3);
+            format!("rgba({r}, {g}, {b}, {alpha})")
+        })
+    }
+
+    /// Parse a CSS `linear-gradient` into an interpolator over `0.0..=100.0`, with each stop at its percentage.  
+    /// Colors may be given as `rgb()`, `rgba()` or `#hex`, and are fully opaque unless they include an alpha;
+    /// see [`Self::to_css_gradient`] for the reverse.
+    ///
+    /// Stops without a position are spread evenly between their neighbours, and the first and last colors
+    /// are held out to 0% and 100% if their stops are inset, as in CSS.  
+    /// A leading direction or angle is skipped, since the interpolator has no direction. Named colors are not supported.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] with the line and column of the first syntax error,
+    /// or of a stop whose position cannot be represented by type S.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let gradient =
+    ///     LinearInterpolator::<4, f64, u8>::from_css_gradient("linear-gradient(to right, rgba(255, 0, 0, 0.5), #0000ff)").unwrap();
+    /// assert_eq!(gradient.interpolate(50.0), [128, 0, 127, 191]);
+    /// ```
+    pub fn from_css_gradient(input: &str) -> Result<Self, ParseError> {
+        let (positions, colors) = parse_css_gradient(input)?;
+        Ok(Self::from_stops(&positions, &colors))
+    }
+}
+
+impl<const N: usize, S: Numeric> LinearInterpolator<'_, N, S, u8> {
+    /// Write each stop as `color position%`, inside a left-to-right `linear-gradient`
+    fn css_gradient(&self, color: impl Fn(&[u8; N]) -> String) -> String {
+        let stops: Vec<_> = self
+            .stops_iter()
+            .map(|(s, values)| {
+                let percent = round_to(self.domain_progress(s) * 100.0, 2);
+                format!("{} {percent}%", color(values))
+            })
+            .collect();
+
+        format!("linear-gradient(to right, {})", stops.join(", "))
+    }
+}
+
+/// Parse a `linear-gradient` into its stop positions and RGBA colors, with missing positions filled in
+fn parse_css_gradient<S: Numeric>(input: &str) -> Result<(Vec<S>, Vec<[u8; 4]>), ParseError> {
+    let mut parser = Parser::new(input);
+    parser.expect("linear-gradient", "`linear-gradient`")?;
+    parser.expect("(", "`(`")?;
+
+    skip_direction(&mut parser)?;
+
+    let mut stops = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        let error = parser.error("a position that fits in the range type");
+        let color = color(&mut parser)?;
+
+        parser.skip_whitespace();
+        let position = if parser
+            .rest()
+            .starts_with(|c: char| c.is_ascii_digit() || "+-.".contains(c))
+        {
+            let position: f64 = parser.number()?;
+            parser.expect("%", "`%`")?;
+            Some(position)
+        } else {
+            None
+        };
+        stops.push((position, color, error));
+
+        parser.skip_whitespace();
+        if !parser.rest().starts_with(',') {
+            break;
+        }
+        parser.bump();
+    }
+
+    parser.expect(")", "`)` or `,`")?;
+    parser.skip_whitespace();
+    if !parser.rest().is_empty() {
+        return Err(parser.error("the end of the gradient"));
+    }
+
+    let percentages = fix_up_positions(stops.iter().map(|(position, ..)| *position).collect());
+    let mut stops: Vec<_> = percentages
+        .into_iter()
+        .zip(stops)
+        .map(|(percent, (_, color, error))| (percent, color, error))
+        .collect();
+
+    // Inset end stops hold their colors out to 0% and 100%, as in CSS, so the domain is always `0..=100`
+    if let Some(&(first, color, error)) = stops.first() {
+        if first > 0.0 {
+            stops.insert(0, (0.0, color, error));
+        }
+    }
+    if let Some(&(last, color, error)) = stops.last() {
+        if last < 100.0 {
+            stops.push((100.0, color, error));
+        }
+    }
+
+    let positions = stops
+        .iter()
+        .map(|(percent, _, error)| S::from_f64(*percent).ok_or(*error))
+        .collect::<Result<_, _>>()?;
+    let colors = stops.iter().map(|(_, color, _)| *color).collect();
+    Ok((positions, colors))
+}
+
+/// Fill in missing stop positions as CSS does: the ends default to 0% and 100%, positions never go backwards,
+/// and runs of missing positions are spread evenly between the stops either side of them
+#[allow(clippy::cast_precision_loss)]
+fn fix_up_positions(mut positions: Vec<Option<f64>>) -> Vec<f64> {
+    if let Some(first @ None) = positions.first_mut() {
+        *first = Some(0.0);
+    }
+    if let Some(last @ None) = positions.last_mut() {
+        *last = Some(100.0);
+    }
+
+    let mut highest = f64::NEG_INFINITY;
+    for position in positions.iter_mut().flatten() {
+        highest = highest.max(*position);
+        *position = highest;
+    }
+
+    let known: Vec<(usize, f64)> = positions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, position)| position.map(|position| (i, position)))
+        .collect();
+
+    let mut filled = Vec::with_capacity(positions.len());
+    for pair in known.windows(2) {
+        let ((start, from), (end, to)) = (pair[0], pair[1]);
+        for i in start..end {
+            filled.push(from + (to - from) * (i - start) as f64 / (end - start) as f64);
+        }
+    }
+    filled.extend(known.last().map(|(_, position)| *position));
+    filled
+}
+
+/// Skip a leading `to <side>` direction or `<number><unit>` angle, and the comma after it, if there is one.  
+/// Anything else is left to be parsed as the first color stop
+fn skip_direction(parser: &mut Parser) -> Result<(), ParseError> {
+    parser.skip_whitespace();
+    let rest = parser.rest();
+
+    if rest
+        .strip_prefix("to")
+        .is_some_and(|side| side.starts_with(char::is_whitespace))
+    {
+        while !parser.rest().starts_with(',') {
+            if parser.rest().is_empty() || parser.rest().starts_with(')') {
+                return Err(parser.error("a color stop"));
+            }
+            parser.bump();
+        }
+        parser.bump();
+        return Ok(());
+    }
+
+    let number_len = rest
+        .find(|c: char| !(c.is_ascii_digit() || "+-.".contains(c)))
+        .unwrap_or(rest.len());
+    let unit = ["deg", "grad", "rad", "turn"]
+        .into_iter()
+        .find(|unit| rest[number_len..].starts_with(unit));
+    if let (true, Some(unit)) = (number_len > 0, unit) {
+        for _ in 0..number_len + unit.len() {
+            parser.bump();
+        }
+        parser.expect(",", "`,`")?;
+    }
+    Ok(())
+}
+
+/// Parse an `rgb()`, `rgba()` or `#hex` color as RGBA
+fn color(parser: &mut Parser) -> Result<[u8; 4], ParseError> {
+    if parser.rest().starts_with('#') {
+        return hex_color(parser);
+    }
+
+    let function = if parser.rest().starts_with("rgba") {
+        "rgba"
+    } else {
+        "rgb"
+    };
+    parser.expect(function, "a color")?;
+    parser.expect("(", "`(`")?;
+
+    let mut rgba = [0, 0, 0, u8::MAX];
+    for (i, channel) in rgba.iter_mut().take(3).enumerate() {
+        if i > 0 {
+            separator(parser, ',');
+        }
+        let value: f64 = parser.number()?;
+        *channel = if parser.rest().starts_with('%') {
+            parser.bump();
+            to_channel(value / 100.0)
+        } else {
+            to_channel(value / 255.0)
+        };
+    }
+
+    parser.skip_whitespace();
+    if parser.rest().starts_with([',', '/']) {
+        parser.bump();
+        let alpha: f64 = parser.number()?;
+        rgba[3] = if parser.rest().starts_with('%') {
+            parser.bump();
+            to_channel(alpha / 100.0)
+        } else {
+            to_channel(alpha)
+        };
+    }
+
+    parser.expect(")", "`)`")?;
+    Ok(rgba)
+}
+
+/// Parse a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` color as RGBA
+fn hex_color(parser: &mut Parser) -> Result<[u8; 4], ParseError> {
+    parser.bump();
+    let error = parser.error("a 3, 4, 6 or 8 digit hex color");
+    let digits: Vec<u8> = parser
+        .rest()
+        .chars()
+        .map_while(|c| c.to_digit(16).and_then(|digit| u8::try_from(digit).ok()))
+        .collect();
+    for _ in &digits {
+        parser.bump();
+    }
+
+    let mut rgba = [0, 0, 0, u8::MAX];
+    match digits.len() {
+        3 | 4 => {
+            for (channel, digit) in rgba.iter_mut().zip(&digits) {
+                *channel = digit * 17;
+            }
+        }
+        6 | 8 => {
+            for (channel, pair) in rgba.iter_mut().zip(digits.chunks(2)) {
+                *channel = pair[0] * 16 + pair[1];
+            }
+        }
+        _ => return Err(error),
+    }
+    Ok(rgba)
+}
+
+/// Skip whitespace, and an optional separator after it; CSS colors allow either commas or spaces
+fn separator(parser: &mut Parser, separator: char) {
+    parser.skip_whitespace();
+    if parser.rest().starts_with(separator) {
+        parser.bump();
+    }
+}
+
+/// Convert a fraction in `0.0..=1.0` to a channel in `0..=255`, clamping values outside of it
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_channel(fraction: f64) -> u8 {
+    (fraction.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Round to the given number of decimal places, so that formatting drops float noise and trailing zeros
+fn round_to(value: f64, places: i32) -> f64 {
+    let scale = 10f64.powi(places);
+    (value * scale).round() / scale
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_css_gradient() {
+        // Positions are relative to the start of the range, even when reversed
+        let interpolator = LinearInterpolator::new(
+            (30u8, 0),
+            &[[0u8, 0, 0], [10, 20, 30], [255, 255, 255], [1, 2, 3]],
+        );
+        assert_eq!(
+            interpolator.to_css_gradient(),
+            "linear-gradient(to right, rgb(0, 0, 0) 0%, rgb(10, 20, 30) 33.33%, rgb(255, 255, 255) 66.67%, rgb(1, 2, 3) 100%)"
+        );
+
+        let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0u8, 0, 0, 128], [0, 0, 0, 64]]);
+        assert_eq!(
+            interpolator.to_css_gradient(),
+            "linear-gradient(to right, rgba(0, 0, 0, 0.502) 0%, rgba(0, 0, 0, 0.251) 100%)"
+        );
+    }
+
+    #[test]
+    fn test_from_css_gradient() {
+        // Directions and angles are skipped
+        for direction in [
+            "to left",
+            "to bottom right",
+            "-0.25turn",
+            "1.5rad",
+            "100grad",
+            "45deg",
+        ] {
+            let gradient = LinearInterpolator::<3, f64, u8>::from_css_gradient(&format!(
+                "linear-gradient({direction}, #f00, #00f)"
+            ))
+            .unwrap();
+            assert_eq!(gradient.value_sets(), vec![[255, 0, 0], [0, 0, 255]]);
+        }
+
+        // Colors in each syntax, with and without positions
+        let gradient = LinearInterpolator::<4, f64, u8>::from_css_gradient(
+            "linear-gradient(rgb(255 0 0 / 50%), rgba(0%, 100%, 0%, 0.2) 20%, #00f 20%, #0000ff80, #12345678 100%)",
+        )
+        .unwrap();
+        assert_eq!(
+            gradient.value_sets(),
+            vec![
+                [255, 0, 0, 128],
+                [0, 255, 0, 51],
+                [0, 0, 255, 255],
+                [0, 0, 255, 128],
+                [0x12, 0x34, 0x56, 0x78]
+            ]
+        );
+        assert_eq!(
+            gradient
+                .buckets()
+                .iter()
+                .map(crate::InterpolationBucket::start)
+                .collect::<Vec<_>>(),
+            [0.0, 20.0, 20.0, 60.0]
+        );
+
+        // Round trips through the exporter
+        let original =
+            LinearInterpolator::new(0.0..=100.0, &[[10u8, 20, 30], [40, 50, 60], [70, 80, 90]]);
+        let parsed =
+            LinearInterpolator::<3, _, _>::from_css_gradient(&original.to_css_gradient()).unwrap();
+        assert_eq!(parsed, original);
+
+        // Inset end stops are held out to 0% and 100%
+        let gradient = LinearInterpolator::<3, f64, u8>::from_css_gradient(
+            "linear-gradient(#f00 20%, #00f 80%)",
+        )
+        .unwrap();
+        assert_eq!(
+            gradient.value_sets(),
+            vec![[255, 0, 0], [255, 0, 0], [0, 0, 255], [0, 0, 255]]
+        );
+        assert_eq!(
+            gradient
+                .buckets()
+                .iter()
+                .map(crate::InterpolationBucket::start)
+                .collect::<Vec<_>>(),
+            [0.0, 20.0, 80.0]
+        );
+        assert_eq!(gradient.interpolate(10.0), [255, 0, 0]);
+        assert_eq!(gradient.interpolate(50.0), [128, 0, 127]);
+        let parsed =
+            LinearInterpolator::<3, _, _>::from_css_gradient(&gradient.to_css_gradient()).unwrap();
+        assert_eq!(parsed, gradient);
+    }
+
+    #[test]
+    fn test_fix_up_positions() {
+        assert_eq!(fix_up_positions(vec![None, None, None]), [0.0, 50.0, 100.0]);
+        assert_eq!(
+            fix_up_positions(vec![Some(10.0), None, None, Some(40.0), None]),
+            [10.0, 20.0, 30.0, 40.0, 100.0]
+        );
+
+        // Positions never go backwards
+        assert_eq!(
+            fix_up_positions(vec![Some(50.0), Some(20.0), None]),
+            [50.0, 50.0, 100.0]
+        );
+        assert!(fix_up_positions(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_css_gradient_errors() {
+        fn error(input: &str) -> (usize, usize, &'static str) {
+            let error = LinearInterpolator::<3, u8, u8>::from_css_gradient(input).unwrap_err();
+            (error.line, error.column, error.expected)
+        }
+
+        assert_eq!(error("radial-gradient(#fff)"), (1, 1, "`linear-gradient`"));
+        assert_eq!(error("linear-gradient(to right)"), (1, 25, "a color stop"));
+        assert_eq!(
+            error("linear-gradient(red 20%, #00f 80%)"),
+            (1, 17, "a color")
+        );
+        assert_eq!(error("linear-gradient(90, #f00)"), (1, 17, "a color"));
+        assert_eq!(error("linear-gradient(90deg #f00)"), (1, 23, "`,`"));
+        assert_eq!(
+            error("linear-gradient(#ff, #fff)"),
+            (1, 18, "a 3, 4, 6 or 8 digit hex color")
+        );
+        assert_eq!(
+            error("linear-gradient(rgb(0, 0), #fff)"),
+            (1, 25, "a number")
+        );
+        assert_eq!(error("linear-gradient(#fff 50, #000)"), (1, 24, "`%`"));
+        assert_eq!(error("linear-gradient(#fff #000)"), (1, 22, "`)` or `,`"));
+        assert_eq!(
+            error("linear-gradient(#fff) x"),
+            (1, 23, "the end of the gradient")
+        );
+        assert_eq!(
+            error("linear-gradient(#fff -10%, #000)"),
+            (1, 17, "a position that fits in the range type")
+        );
+    }
+}
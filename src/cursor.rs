@@ -0,0 +1,102 @@
+use crate::{LinearInterpolator, Numeric};
+
+/// An iterator that steps through the range of a [`LinearInterpolator`], interpolating at each position.
+///
+/// The first item is at the start of the range, and each following item advances by `step` towards the end;  
+/// Iteration stops once the next position would pass the end of the range.  
+/// Reversed ranges are stepped through in reverse, so `step` is always a positive distance.
+///
+/// A zero step yields only the start of the range.
+///
+/// # Example
+/// ```rust
+/// use lineic::LinearInterpolator;
+///
+/// let interpolator = LinearInterpolator::new(10.0..=0.0, &[[0.0], [100.0]]);
+/// let frames: Vec<_> = interpolator.cursor(2.5).collect();
+/// assert_eq!(frames, vec![[0.0], [25.0], [50.0], [75.0], [100.0]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct InterpolationCursor<'a, const N: usize, S: Numeric, T: Numeric> {
+    interpolator: &'a LinearInterpolator<'a, N, S, T>,
+    position: Option<S>,
+    step: S,
+}
+impl<'a, const N: usize, S: Numeric, T: Numeric> InterpolationCursor<'a, N, S, T> {
+    /// Create a new cursor at the start of the interpolator's range, advancing by `step` on each call to `next`.
+    pub fn new(interpolator: &'a LinearInterpolator<'a, N, S, T>, step: S) -> Self {
+        Self {
+            interpolator,
+            position: Some(interpolator.domain().start),
+            step: step.abs(),
+        }
+    }
+
+    /// Get the position that will be interpolated on the next call to `next`.  
+    /// Returns None once the cursor has passed the end of the range.
+    pub fn position(&self) -> Option<S> {
+        self.position.clone()
+    }
+
+    /// Get the distance the cursor advances on each step.
+    pub fn step(&self) -> S {
+        self.step.clone()
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> Iterator for InterpolationCursor<'_, N, S, T> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.position.take()?;
+        let domain = self.interpolator.domain();
+
+        let next = if domain.is_reversed() {
+            s.clone().checked_sub(self.step.clone())
+        } else {
+            s.clone().checked_add(self.step.clone())
+        };
+        self.position = next.filter(|next| *next != s && domain.contains(next.clone()));
+
+        Some(self.interpolator.interpolate(s))
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> LinearInterpolator<'_, N, S, T> {
+    /// Create an [`InterpolationCursor`] that steps through this interpolator's range by `step`.
+    pub fn cursor(&self, step: S) -> InterpolationCursor<'_, N, S, T> {
+        InterpolationCursor::new(self, step)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cursor() {
+        let interpolator = LinearInterpolator::new(0u8..=10, &[[0u8], [100]]);
+        let frames: Vec<_> = interpolator.cursor(3).collect();
+        assert_eq!(frames, vec![[0], [30], [60], [90]]);
+
+        // Reversed ranges step backwards, and stop at the end without overflowing
+        let interpolator = LinearInterpolator::new((255u8, 0), &[[0u8], [255]]);
+        let mut cursor = interpolator.cursor(85);
+        assert_eq!(cursor.position(), Some(255));
+        assert_eq!(cursor.next(), Some([0]));
+        assert_eq!(cursor.by_ref().count(), 3);
+        assert_eq!(cursor.position(), None);
+        assert_eq!(cursor.next(), None);
+
+        // A zero step only yields the start
+        assert_eq!(interpolator.cursor(0).count(), 1);
+
+        // 'static interpolators can be stepped through without a 'static borrow
+        let interpolator: LinearInterpolator<'static, 1, u8, u8> =
+            LinearInterpolator::new(0..=10, &[[0], [10]]);
+        assert_eq!(
+            interpolator.cursor(5).collect::<Vec<_>>(),
+            vec![[0], [5], [10]]
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! Easing functions, which reshape the progress through a bucket before its values are blended.
+//!
+//! Any type implementing [`Easing`] can be passed to [`crate::InterpolationBucket::interpolate_with_easing`],
+//! including closures of the form `Fn(f64) -> f64`.
+//!
+//! # Example
+//! ```rust
+//! use lineic::{easing::EaseInCubic, InterpolationBucket};
+//!
+//! let bucket = InterpolationBucket::new(0.0..=10.0, [0.0], [100.0]);
+//! assert_eq!(bucket.interpolate_with_easing(5.0, &EaseInCubic), [12.5]);
+//!
+//! // A custom easing, snapping to quarters
+//! let quarters = |t: f64| (t * 4.0).floor() / 4.0;
+//! assert_eq!(bucket.interpolate_with_easing(6.0, &quarters), [50.0]);
+//! ```
+
+/// Maps linear progress through a bucket onto eased progress.
+///
+/// `t` is in `0.0..=1.0`, and implementations should return `0.0` at `0.0` and `1.0` at `1.0`.
+/// Results outside of `0.0..=1.0` are clamped, so the interpolated values never overshoot the stops,
+/// unless [`Easing::overshoots`] is true.
+pub trait Easing {
+    /// Ease the given progress, from `0.0` at the start of a bucket to `1.0` at its end
+    fn ease(&self, t: f64) -> f64;
+
+    /// Whether the eased progress may leave `0.0..=1.0`, such as for [`BackOut`].  
+    /// If true the results are not clamped, so the interpolated values can briefly pass the stops,
+    /// saturating at the bounds of the value type. Defaults to false.
+    fn overshoots(&self) -> bool {
+        false
+    }
+}
+
+/// The amount the back easings pull away from their endpoints; overshooting by about 10%
+pub const BACK_OVERSHOOT: f64 = 1.70158;
+
+impl<F: Fn(f64) -> f64> Easing for F {
+    fn ease(&self, t: f64) -> f64 {
+        self(t)
+    }
+}
+
+/// No easing; progress is unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Linear;
+impl Easing for Linear {
+    fn ease(&self, t: f64) -> f64 {
+        t
+    }
+}
+
+/// Accelerates through the first half of the bucket, and decelerates through the second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EaseInOutQuad;
+impl Easing for EaseInOutQuad {
+    fn ease(&self, t: f64) -> f64 {
+        if t < 0.5 {
+            2.0 * t * t
+        } else {
+            let u = 2.0 - 2.0 * t;
+            1.0 - u * u / 2.0
+        }
+    }
+}
+
+/// Starts slowly, accelerating towards the end of the bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EaseInCubic;
+impl Easing for EaseInCubic {
+    fn ease(&self, t: f64) -> f64 {
+        t * t * t
+    }
+}
+
+/// Pulls back past the start of the bucket before accelerating towards its end.  
+/// The eased progress dips below `0.0`; see [`Easing::overshoots`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BackIn;
+impl Easing for BackIn {
+    fn ease(&self, t: f64) -> f64 {
+        (BACK_OVERSHOOT + 1.0) * t * t * t - BACK_OVERSHOOT * t * t
+    }
+
+    fn overshoots(&self) -> bool {
+        true
+    }
+}
+
+/// Overshoots past the end of the bucket before settling back onto it.  
+/// The eased progress rises above `1.0`; see [`Easing::overshoots`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BackOut;
+impl Easing for BackOut {
+    fn ease(&self, t: f64) -> f64 {
+        1.0 - BackIn.ease(1.0 - t)
+    }
+
+    fn overshoots(&self) -> bool {
+        true
+    }
+}
+
+/// Pulls back past the start of the bucket, then overshoots past its end before settling.  
+/// The eased progress leaves `0.0..=1.0` at both ends; see [`Easing::overshoots`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BackInOut;
+impl Easing for BackInOut {
+    fn ease(&self, t: f64) -> f64 {
+        // The standard in-out variant pulls back further, so each half overshoots by about 10%
+        let overshoot = BACK_OVERSHOOT * 1.525;
+        let back_in = |t: f64| (overshoot + 1.0) * t * t * t - overshoot * t * t;
+        if t < 0.5 {
+            back_in(2.0 * t) / 2.0
+        } else {
+            1.0 - back_in(2.0 - 2.0 * t) / 2.0
+        }
+    }
+
+    fn overshoots(&self) -> bool {
+        true
+    }
+}
+
+/// Overshoots past the end of the bucket, then oscillates around it with a decaying sine wave.  
+/// The eased progress rises above `1.0`; see [`Easing::overshoots`].
+///
+/// The decay and the sine wave are computed with series expansions rather than the standard library,
+/// so that this is also available with the `no_std` feature; results agree with `powf` and `sin` to within about `1e-12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ElasticOut;
+impl Easing for ElasticOut {
+    fn ease(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            let period = core::f64::consts::TAU / 3.0;
+            exp2(-10.0 * t) * sin((10.0 * t - 0.75) * period) + 1.0
+        }
+    }
+
+    fn overshoots(&self) -> bool {
+        true
+    }
+}
+
+/// Reaches the end of the bucket early, then bounces back off it a few times before settling.  
+/// Unlike [`ElasticOut`], the eased progress stays within `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BounceOut;
+impl Easing for BounceOut {
+    fn ease(&self, t: f64) -> f64 {
+        // Each bounce is a parabola, with a quarter of the height of the one before it
+        const SCALE: f64 = 7.5625;
+        const WIDTH: f64 = 2.75;
+
+        let (offset, floor) = if t < 1.0 / WIDTH {
+            (0.0, 0.0)
+        } else if t < 2.0 / WIDTH {
+            (1.5 / WIDTH, 0.75)
+        } else if t < 2.5 / WIDTH {
+            (2.25 / WIDTH, 0.9375)
+        } else {
+            (2.625 / WIDTH, 0.984_375)
+        };
+
+        let t = t - offset;
+        SCALE * t * t + floor
+    }
+}
+
+/// One of the built-in easings, as a value.  
+/// Unlike the unit structs, these can be mixed in one array, such as to ease each channel differently.
+///
+/// # Example
+/// ```rust
+/// use lineic::{easing::EasingMode, InterpolationBucket};
+///
+/// let bucket = InterpolationBucket::new(0.0..=10.0, [0.0, 0.0], [100.0, 100.0]);
+/// let easings = [EasingMode::EaseInCubic, EasingMode::Linear];
+/// assert_eq!(bucket.interpolate_per_channel_easing(5.0, &easings), [12.5, 50.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EasingMode {
+    /// See [`Linear`]
+    #[default]
+    Linear,
+
+    /// See [`EaseInOutQuad`]
+    EaseInOutQuad,
+
+    /// See [`EaseInCubic`]
+    EaseInCubic,
+
+    /// See [`BackIn`]
+    BackIn,
+
+    /// See [`BackOut`]
+    BackOut,
+
+    /// See [`BackInOut`]
+    BackInOut,
+
+    /// See [`ElasticOut`]
+    ElasticOut,
+
+    /// See [`BounceOut`]
+    BounceOut,
+}
+impl Easing for EasingMode {
+    fn ease(&self, t: f64) -> f64 {
+        match self {
+            Self::Linear => Linear.ease(t),
+            Self::EaseInOutQuad => EaseInOutQuad.ease(t),
+            Self::EaseInCubic => EaseInCubic.ease(t),
+            Self::BackIn => BackIn.ease(t),
+            Self::BackOut => BackOut.ease(t),
+            Self::BackInOut => BackInOut.ease(t),
+            Self::ElasticOut => ElasticOut.ease(t),
+            Self::BounceOut => BounceOut.ease(t),
+        }
+    }
+
+    fn overshoots(&self) -> bool {
+        matches!(
+            self,
+            Self::BackIn | Self::BackOut | Self::BackInOut | Self::ElasticOut
+        )
+    }
+}
+
+/// `2^x`, without the standard library.  
+/// The whole part of `x` is applied to the exponent directly, and the fraction with a Taylor series of `e^(x ln 2)`.
+#[allow(clippy::cast_possible_truncation)] // Only called with small exponents
+fn exp2(x: f64) -> f64 {
+    let whole = floor(x);
+    let z = (x - whole) * core::f64::consts::LN_2;
+
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for n in 1..=20 {
+        term *= z / f64::from(n);
+        sum += term;
+    }
+
+    let whole = (whole as i32).clamp(-1022, 1023);
+    sum * f64::from_bits(u64::from((whole + 1023).unsigned_abs()) << 52)
+}
+
+/// `sin(x)`, without the standard library.  
+/// `x` is first reduced into `-PI..=PI`, then the Taylor series is summed until its terms vanish.
+fn sin(x: f64) -> f64 {
+    use core::f64::consts::{PI, TAU};
+    let x = x - floor((x + PI) / TAU) * TAU;
+
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..=15 {
+        term *= -x * x / f64::from((2 * n) * (2 * n + 1));
+        sum += term;
+    }
+    sum
+}
+
+/// Round `x` down to a whole number, without the standard library
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)] // Only called with small values
+fn floor(x: f64) -> f64 {
+    let whole = x as i64 as f64;
+    if whole > x {
+        whole - 1.0
+    } else {
+        whole
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_builtin_easings() {
+        for t in [0.0, 1.0] {
+            assert_eq!(Linear.ease(t), t);
+            assert_eq!(EaseInOutQuad.ease(t), t);
+            assert_eq!(EaseInCubic.ease(t), t);
+        }
+
+        assert_eq!(Linear.ease(0.25), 0.25);
+        assert_eq!(EaseInOutQuad.ease(0.25), 0.125);
+        assert_eq!(EaseInOutQuad.ease(0.5), 0.5);
+        assert_eq!(EaseInOutQuad.ease(0.75), 0.875);
+        assert_eq!(EaseInCubic.ease(0.5), 0.125);
+
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert_eq!(EasingMode::Linear.ease(t), Linear.ease(t));
+            assert_eq!(EasingMode::EaseInOutQuad.ease(t), EaseInOutQuad.ease(t));
+            assert_eq!(EasingMode::EaseInCubic.ease(t), EaseInCubic.ease(t));
+            assert_eq!(EasingMode::BackIn.ease(t), BackIn.ease(t));
+            assert_eq!(EasingMode::BackOut.ease(t), BackOut.ease(t));
+            assert_eq!(EasingMode::BackInOut.ease(t), BackInOut.ease(t));
+        }
+
+        assert!(!EasingMode::Linear.overshoots());
+        assert!(EasingMode::BackInOut.overshoots());
+    }
+
+    #[test]
+    fn test_back_easings() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        for easing in [
+            EasingMode::BackIn,
+            EasingMode::BackOut,
+            EasingMode::BackInOut,
+        ] {
+            assert!(close(easing.ease(0.0), 0.0));
+            assert!(close(easing.ease(1.0), 1.0));
+            assert!(easing.overshoots());
+        }
+
+        // Each curve passes its endpoints by about 10%
+        assert!(close(BackIn.ease(0.2), -0.046_450_56));
+        assert!(close(BackOut.ease(0.8), 1.046_450_56));
+        assert!(BackInOut.ease(0.1) < 0.0);
+        assert!(BackInOut.ease(0.9) > 1.0);
+        assert!(close(BackInOut.ease(0.5), 0.5));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_bounce_out() {
+        assert_eq!(BounceOut.ease(0.0), 0.0);
+        assert_eq!(BounceOut.ease(1.0), 1.0);
+        assert_eq!(EasingMode::BounceOut.ease(0.5), BounceOut.ease(0.5));
+        assert!(!EasingMode::BounceOut.overshoots());
+
+        // Touches the end at each bounce, without passing it
+        assert!((BounceOut.ease(1.0 / 2.75) - 1.0).abs() < 1e-12);
+        for i in 0..=100 {
+            let eased = BounceOut.ease(f64::from(i) / 100.0);
+            assert!((0.0..=1.0 + 1e-12).contains(&eased));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_elastic_out() {
+        assert_eq!(ElasticOut.ease(0.0), 0.0);
+        assert_eq!(ElasticOut.ease(1.0), 1.0);
+        assert_eq!(EasingMode::ElasticOut.ease(0.3), ElasticOut.ease(0.3));
+        assert!(EasingMode::ElasticOut.overshoots());
+
+        // The first swing passes the end, and the oscillation decays towards it
+        assert!(ElasticOut.ease(0.1) > 1.0);
+        assert!((ElasticOut.ease(0.9) - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_elastic_out_matches_std() {
+        for i in 1..100 {
+            let t = f64::from(i) / 100.0;
+            let period = std::f64::consts::TAU / 3.0;
+            let expected = 2f64.powf(-10.0 * t) * ((10.0 * t - 0.75) * period).sin() + 1.0;
+            assert!((ElasticOut.ease(t) - expected).abs() < 1e-12, "{t}");
+        }
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(not(feature = "no_std"))]
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "no_std")]
+use core::fmt::{Display, Formatter, Result};
+
+/// An error describing a broken invariant in an interpolator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolatorError {
+    /// The interpolator has no buckets
+    NoBuckets,
+
+    /// The end of the bucket at `index` is not the start of the bucket after it
+    Discontinuous {
+        /// Index of the first bucket in the mismatched pair
+        index: usize,
+    },
+
+    /// The bucket at `index` runs in the opposite direction to the interpolator's range
+    Misoriented {
+        /// Index of the offending bucket
+        index: usize,
+    },
+
+    /// The value set at `index` does not have exactly one value per channel
+    ValueSetLength {
+        /// Index of the offending value set
+        index: usize,
+
+        /// The number of values it has
+        len: usize,
+
+        /// The number of channels in the interpolator
+        expected: usize,
+    },
+
+    /// There are too many value sets for the number of buckets to be represented by the range type
+    TooManyValueSets,
+}
+
+impl Display for InterpolatorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::NoBuckets => write!(f, "interpolator has no buckets"),
+            Self::Discontinuous { index } => write!(
+                f,
+                "bucket {index} does not end where bucket {} starts",
+                index + 1
+            ),
+            Self::Misoriented { index } => write!(
+                f,
+                "bucket {index} runs in the opposite direction to the interpolator's range"
+            ),
+            Self::ValueSetLength {
+                index,
+                len,
+                expected,
+            } => write!(
+                f,
+                "value set {index} has {len} values, but the interpolator has {expected} channels"
+            ),
+            Self::TooManyValueSets => {
+                write!(f, "too many value sets for the interpolator's range type")
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for InterpolatorError {}
+
+/// An error produced when parsing an interpolator from a string, with the 1-based position where it occurred.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    /// The line on which the error occurred
+    pub line: usize,
+
+    /// The column at which the error occurred, in characters
+    pub column: usize,
+
+    /// A description of what was expected at that position
+    pub expected: &'static str,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected, self.line, self.column
+        )
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ParseError {}
+
+/// An error produced when importing an interpolator from a gradient file.
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file could not be read, or was not valid UTF-8
+    Io(std::io::Error),
+
+    /// The file was read, but its contents are not a valid gradient
+    Parse(ParseError),
+}
+
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+impl Display for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read gradient: {error}"),
+            Self::Parse(error) => write!(f, "invalid gradient: {error}"),
+        }
+    }
+}
+
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+impl std::error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+impl From<std::io::Error> for ImportError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+impl From<ParseError> for ImportError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+/// An error produced by [`crate::LinearInterpolator::try_interpolate`] when the input lies outside of the interpolator's range.  
+/// The input was clamped to the nearest end of the range, so `clamped_to` can still be interpolated for a usable result.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfDomain<S> {
+    /// The input that was outside of the range
+    pub value: S,
+
+    /// The end of the range the input was clamped to
+    pub clamped_to: S,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<S: Display> Display for OutOfDomain<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{} is outside of the interpolator's range, clamped to {}",
+            self.value, self.clamped_to
+        )
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<S: Display + std::fmt::Debug> std::error::Error for OutOfDomain<S> {}
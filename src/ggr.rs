@@ -0,0 +1,400 @@
+//! Import of GIMP gradient (`.ggr`) files as RGBA interpolators.
+//!
+//! Each segment of the file becomes one or more buckets over `0.0..=1.0`.  
+//! Linear RGB segments and step segments map onto buckets exactly; other blend functions and HSV segments are sampled.
+use crate::{
+    colorspace::{hsv_to_rgb, rgb_to_hsv},
+    parse::Parser,
+    ImportError, LinearInterpolator, ParseError,
+};
+use std::{
+    f64::consts::{FRAC_PI_2, PI},
+    io::Read,
+};
+
+/// Number of buckets used to approximate a segment that does not blend linearly in RGB
+const SEGMENT_SAMPLES: usize = 16;
+
+/// Widths below which GIMP treats a segment, or half of one, as a single point
+const SEGMENT_EPSILON: f64 = 1e-10;
+
+impl LinearInterpolator<'_, 4, f64, u8> {
+    /// Read a GIMP gradient (`.ggr`) file into an RGBA interpolator over `0.0..=1.0`, with stops where the file's segments meet.
+    ///
+    /// Linear segments blending in RGB become 2 buckets, meeting at the segment's midpoint, and step segments become a hard edge.  
+    /// Curved, sine and spherical segments, and segments blending in HSV, are approximated with 16 buckets each.
+    /// Where neighbouring segments meet with different colors, the gradient jumps between them as in GIMP.
+    ///
+    /// Endpoints tied to GIMP's foreground or background color use the fallback color stored in the file.
+    ///
+    /// # Errors
+    /// Returns [`ImportError::Io`] if the file cannot be read, or is not valid UTF-8.  
+    /// Returns [`ImportError::Parse`] with the line and column of the first syntax error,
+    /// or of a segment whose positions are out of order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let file = "GIMP Gradient\nName: Red to blue\n1\n0.0 0.25 1.0 1 0 0 1 0 0 1 1 0 0\n";
+    /// let gradient = LinearInterpolator::from_ggr(file.as_bytes()).unwrap();
+    ///
+    /// // The midpoint is moved towards the start of the segment
+    /// assert_eq!(gradient.interpolate(0.25), [128, 0, 128, 255]);
+    /// assert_eq!(gradient.interpolate(1.0), [0, 0, 255, 255]);
+    /// ```
+    pub fn from_ggr(mut reader: impl Read) -> Result<Self, ImportError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+
+        let (positions, colors) = parse_ggr(&input)?;
+        Ok(Self::from_stops(&positions, &colors))
+    }
+}
+
+/// How a segment moves from its left color to its right color, as a function of position
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Blend {
+    Linear,
+    Curved,
+    Sine,
+    SphereIncreasing,
+    SphereDecreasing,
+    Step,
+}
+impl Blend {
+    fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => Self::Linear,
+            1 => Self::Curved,
+            2 => Self::Sine,
+            3 => Self::SphereIncreasing,
+            4 => Self::SphereDecreasing,
+            5 => Self::Step,
+            _ => return None,
+        })
+    }
+
+    /// Get how far from the left color to the right color a segment is at `pos`, given its midpoint.  
+    /// Both are relative to the segment, from `0.0` at its left to `1.0` at its right.
+    fn factor(self, middle: f64, pos: f64) -> f64 {
+        match self {
+            Self::Linear => linear_factor(middle, pos),
+            Self::Curved => pos.powf(0.5f64.ln() / middle.max(SEGMENT_EPSILON).ln()),
+            Self::Sine => {
+                f64::midpoint(f64::sin(-FRAC_PI_2 + PI * linear_factor(middle, pos)), 1.0)
+            }
+            Self::SphereIncreasing => {
+                let factor = linear_factor(middle, pos) - 1.0;
+                (1.0 - factor * factor).sqrt()
+            }
+            Self::SphereDecreasing => {
+                let factor = linear_factor(middle, pos);
+                1.0 - (1.0 - factor * factor).sqrt()
+            }
+            Self::Step => {
+                if pos >= middle {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// A linear blend that reaches `0.5` at `middle`
+fn linear_factor(middle: f64, pos: f64) -> f64 {
+    if pos <= middle {
+        if middle < SEGMENT_EPSILON {
+            0.0
+        } else {
+            0.5 * pos / middle
+        }
+    } else {
+        let (pos, middle) = (pos - middle, 1.0 - middle);
+        if middle < SEGMENT_EPSILON {
+            1.0
+        } else {
+            0.5 + 0.5 * pos / middle
+        }
+    }
+}
+
+/// The path a segment's colors take between its ends
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Coloring {
+    Rgb,
+
+    /// Hue increases from the left color to the right
+    HsvCounterClockwise,
+
+    /// Hue decreases from the left color to the right
+    HsvClockwise,
+}
+impl Coloring {
+    fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => Self::Rgb,
+            1 => Self::HsvCounterClockwise,
+            2 => Self::HsvClockwise,
+            _ => return None,
+        })
+    }
+}
+
+/// A single segment of a gradient file, with RGBA colors in `0.0..=1.0`
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    left: f64,
+    middle: f64,
+    right: f64,
+    colors: [[f64; 4]; 2],
+    blend: Blend,
+    coloring: Coloring,
+}
+impl Segment {
+    /// Get the color `factor` of the way from the left color to the right
+    fn color(&self, factor: f64) -> [u8; 4] {
+        let [lo, hi] = &self.colors;
+        let lerp = |lo: f64, hi: f64| lo + (hi - lo) * factor;
+
+        let [r, g, b] = match self.coloring {
+            Coloring::Rgb => [lerp(lo[0], hi[0]), lerp(lo[1], hi[1]), lerp(lo[2], hi[2])],
+            coloring => {
+                let lo = rgb_to_hsv([lo[0], lo[1], lo[2]]);
+                let hi = rgb_to_hsv([hi[0], hi[1], hi[2]]);
+
+                let mut delta = hi[0] - lo[0];
+                if coloring == Coloring::HsvCounterClockwise && delta < 0.0 {
+                    delta += 360.0;
+                } else if coloring == Coloring::HsvClockwise && delta > 0.0 {
+                    delta -= 360.0;
+                }
+
+                let hue = (lo[0] + delta * factor).rem_euclid(360.0);
+                hsv_to_rgb([hue, lerp(lo[1], hi[1]), lerp(lo[2], hi[2])])
+            }
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        [r, g, b, lerp(lo[3], hi[3])].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Get the stops that make up this segment, as `(position, color)` pairs
+    #[allow(clippy::cast_precision_loss)]
+    fn stops(&self) -> Vec<(f64, [u8; 4])> {
+        let width = self.right - self.left;
+        let middle = if width < SEGMENT_EPSILON {
+            0.5
+        } else {
+            (self.middle - self.left) / width
+        };
+
+        let factors = match (self.blend, self.coloring) {
+            (Blend::Step, _) => vec![(0.0, 0.0), (middle, 0.0), (middle, 1.0), (1.0, 1.0)],
+            (Blend::Linear, Coloring::Rgb) => vec![(0.0, 0.0), (middle, 0.5), (1.0, 1.0)],
+            (blend, _) => (0..=SEGMENT_SAMPLES)
+                .map(|i| {
+                    let pos = i as f64 / SEGMENT_SAMPLES as f64;
+                    (pos, blend.factor(middle, pos))
+                })
+                .collect(),
+        };
+
+        factors
+            .into_iter()
+            .map(|(pos, factor)| (self.left + width * pos, self.color(factor)))
+            .collect()
+    }
+}
+
+/// Parse a gradient file into its stop positions and RGBA colors
+fn parse_ggr(input: &str) -> Result<(Vec<f64>, Vec<[u8; 4]>), ParseError> {
+    let mut parser = Parser::new(input);
+    parser.expect("GIMP Gradient", "`GIMP Gradient`")?;
+
+    // Older files have no name
+    parser.skip_whitespace();
+    if parser.rest().starts_with("Name:") {
+        while !parser.rest().is_empty() && !parser.rest().starts_with('\n') {
+            parser.bump();
+        }
+    }
+
+    parser.skip_whitespace();
+    let error = parser.error("a segment count of at least 1");
+    let count: usize = parser.number()?;
+    if count == 0 {
+        return Err(error);
+    }
+
+    let mut stops: Vec<(f64, [u8; 4])> = Vec::new();
+    let mut previous_right = 0.0;
+    for _ in 0..count {
+        let segment = segment(&mut parser, previous_right)?;
+        previous_right = segment.right;
+        for stop in segment.stops() {
+            // Neighbouring segments that meet with the same color share a stop
+            if stops.last() != Some(&stop) {
+                stops.push(stop);
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+    if !parser.rest().is_empty() {
+        return Err(parser.error("the end of the file"));
+    }
+
+    Ok(stops.into_iter().unzip())
+}
+
+/// Parse a single segment line, which must start no earlier than `previous_right`, where the segment before it ends
+fn segment(parser: &mut Parser, previous_right: f64) -> Result<Segment, ParseError> {
+    parser.skip_whitespace();
+    let error = parser.error("segment positions in order, within 0 to 1");
+    let [left, middle, right] = numbers(parser)?;
+    if !(previous_right <= left && left <= middle && middle <= right && right <= 1.0) {
+        return Err(error);
+    }
+
+    let [r0, g0, b0, a0, r1, g1, b1, a1] = numbers(parser)?;
+
+    parser.skip_whitespace();
+    let error = parser.error("a blend type from 0 to 5");
+    let blend = parser
+        .number()
+        .ok()
+        .and_then(Blend::from_code)
+        .ok_or(error)?;
+
+    parser.skip_whitespace();
+    let error = parser.error("a color type from 0 to 2");
+    let coloring = parser
+        .number()
+        .ok()
+        .and_then(Coloring::from_code)
+        .ok_or(error)?;
+
+    // Newer files add the type of each endpoint's color, which only matters inside GIMP
+    while parser.rest().starts_with([' ', '\t']) {
+        parser.bump();
+    }
+    if parser.rest().starts_with(|c: char| c.is_ascii_digit()) {
+        numbers::<2>(parser)?;
+    }
+
+    Ok(Segment {
+        left,
+        middle,
+        right,
+        colors: [[r0, g0, b0, a0], [r1, g1, b1, a1]],
+        blend,
+        coloring,
+    })
+}
+
+/// Parse `N` whitespace separated numbers
+fn numbers<const N: usize>(parser: &mut Parser) -> Result<[f64; N], ParseError> {
+    let mut values = [0.0; N];
+    for value in &mut values {
+        *value = parser.number()?;
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_ggr() {
+        // Linear black to white, a step from red to green, then a sine blend from red to blue through the hues,
+        // with the endpoint color types added by newer versions of GIMP
+        let file = "GIMP Gradient
+Name: Test
+3
+0.000000 0.250000 0.500000 0 0 0 1 1 1 1 1 0 0
+0.500000 0.625000 0.750000 1 0 0 1 0 1 0 1 5 0
+0.750000 0.875000 1.000000 1 0 0 1 0 0 1 0.5 2 1 0 0
+";
+        let gradient = LinearInterpolator::from_ggr(file.as_bytes()).unwrap();
+        assert_eq!(gradient.interpolate(0.0), [0, 0, 0, 255]);
+        assert_eq!(gradient.interpolate(0.25), [128, 128, 128, 255]);
+        assert_eq!(gradient.interpolate(0.6), [255, 0, 0, 255]);
+        assert_eq!(gradient.interpolate(0.7), [0, 255, 0, 255]);
+        assert_eq!(gradient.interpolate(0.875), [0, 255, 0, 191]);
+        assert_eq!(gradient.interpolate(1.0), [0, 0, 255, 128]);
+
+        // The hard edges between segments are kept, and segments that meet with the same color share a stop
+        let stops: Vec<_> = gradient.stops_iter().map(|(s, _)| s).collect();
+        assert_eq!(&stops[..7], [0.0, 0.25, 0.5, 0.5, 0.625, 0.625, 0.75]);
+        assert_eq!(stops.len(), 7 + 17);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_blend_factors() {
+        let blends = [
+            Blend::Linear,
+            Blend::Curved,
+            Blend::Sine,
+            Blend::SphereIncreasing,
+            Blend::SphereDecreasing,
+            Blend::Step,
+        ];
+        for blend in blends {
+            assert_eq!(blend.factor(0.25, 0.0), 0.0, "{blend:?}");
+            assert_eq!(blend.factor(0.25, 1.0), 1.0, "{blend:?}");
+        }
+
+        // The midpoint is reached at `middle` by every blend except the spherical ones
+        for blend in [Blend::Linear, Blend::Curved, Blend::Sine] {
+            assert!((blend.factor(0.25, 0.25) - 0.5).abs() < 1e-12, "{blend:?}");
+        }
+        assert!(Blend::SphereIncreasing.factor(0.5, 0.5) > 0.5);
+        assert!(Blend::SphereDecreasing.factor(0.5, 0.5) < 0.5);
+        assert_eq!(Blend::Step.factor(0.25, 0.24), 0.0);
+        assert_eq!(Blend::Step.factor(0.25, 0.25), 1.0);
+    }
+
+    #[test]
+    fn test_ggr_errors() {
+        fn error(input: &[u8]) -> ImportError {
+            LinearInterpolator::from_ggr(input).unwrap_err()
+        }
+
+        fn parse_error(input: &str) -> (usize, usize, &'static str) {
+            match error(input.as_bytes()) {
+                ImportError::Parse(e) => (e.line, e.column, e.expected),
+                ImportError::Io(e) => panic!("unexpected I/O error: {e}"),
+            }
+        }
+
+        assert!(matches!(error(b"GIMP Gradient\n\xff"), ImportError::Io(_)));
+        assert_eq!(parse_error("GIMP Palette"), (1, 1, "`GIMP Gradient`"));
+        assert_eq!(
+            parse_error("GIMP Gradient\n0\n"),
+            (2, 1, "a segment count of at least 1")
+        );
+        assert_eq!(
+            parse_error(
+                "GIMP Gradient\n2\n0 0.5 0.6 0 0 0 1 1 1 1 1 0 0\n0.5 0.7 1 0 0 0 1 1 1 1 1 0 0"
+            ),
+            (4, 1, "segment positions in order, within 0 to 1")
+        );
+        assert_eq!(
+            parse_error("GIMP Gradient\n1\n0 0.5 1 0 0 0 1 1 1 1 1 6 0"),
+            (3, 25, "a blend type from 0 to 5")
+        );
+        assert_eq!(
+            parse_error("GIMP Gradient\n1\n0 0.5 1 0 0 0 1 1 1 1 1 0 0\n0 0.5 1"),
+            (4, 1, "the end of the file")
+        );
+        assert_eq!(
+            parse_error("GIMP Gradient\n1\n0 0.5 1 0 0 0 1 1 1"),
+            (3, 20, "a number")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{InterpolationBucket, Numeric, ReversibleRange};
+
+/// A bilinear interpolator across a 2D grid of value sets, indexed by two inputs.
+///
+/// Columns are spread evenly across the `x` range, and rows across the `y` range;  
+/// Each interpolation blends the four value sets surrounding the point `(x, y)`.
+///
+/// Out of range inputs are clamped to the range of their axis.
+///
+/// # Example
+/// ```rust
+/// use lineic::GridInterpolator;
+///
+/// // Corners of a square - black, red, green and yellow
+/// let grid = GridInterpolator::new(
+///     0.0..=10.0,
+///     0.0..=10.0,
+///     &[
+///         [[0.0, 0.0, 0.0], [255.0, 0.0, 0.0]],
+///         [[0.0, 255.0, 0.0], [255.0, 255.0, 0.0]],
+///     ],
+/// );
+///
+/// assert_eq!(grid.interpolate(10.0, 0.0), [255.0, 0.0, 0.0]);
+/// assert_eq!(grid.interpolate(5.0, 5.0), [127.5, 127.5, 0.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridInterpolator<const N: usize, S: Numeric, T: Numeric> {
+    x_range: ReversibleRange<S>,
+    y_range: ReversibleRange<S>,
+    width: usize,
+    value_sets: Vec<[T; N]>,
+}
+impl<const N: usize, S: Numeric, T: Numeric> GridInterpolator<N, S, T> {
+    /// Create a new grid interpolator from a set of rows, each containing `W` value sets.  
+    /// Columns are spread evenly across `x_range`, and rows across `y_range`.
+    ///
+    /// If the grid is empty, every interpolation will return zeros.
+    pub fn new<const W: usize>(
+        x_range: impl Into<ReversibleRange<S>>,
+        y_range: impl Into<ReversibleRange<S>>,
+        rows: &[[[T; N]; W]],
+    ) -> Self {
+        Self {
+            x_range: x_range.into(),
+            y_range: y_range.into(),
+            width: W,
+            value_sets: rows.iter().flatten().cloned().collect(),
+        }
+    }
+
+    /// Get the range of the `x` input, across the columns of the grid.
+    pub fn x_range(&self) -> &ReversibleRange<S> {
+        &self.x_range
+    }
+
+    /// Get the range of the `y` input, across the rows of the grid.
+    pub fn y_range(&self) -> &ReversibleRange<S> {
+        &self.y_range
+    }
+
+    /// Get the number of columns and rows in the grid, as `(width, height)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        match self.width {
+            0 => (0, 0),
+            width => (width, self.value_sets.len() / width),
+        }
+    }
+
+    /// Get the value set at the given column and row, if it exists.
+    pub fn get(&self, column: usize, row: usize) -> Option<&[T; N]> {
+        if column >= self.width {
+            return None;
+        }
+        self.value_sets.get(row * self.width + column)
+    }
+
+    /// Interpolate across the grid at the point `(x, y)`.  
+    /// The four surrounding value sets are blended along `x` first, then along `y`.
+    pub fn interpolate(&self, x: S, y: S) -> [T; N] {
+        let (width, height) = self.dimensions();
+        if width == 0 || height == 0 {
+            return [T::ZERO; N];
+        }
+
+        let (column, u) = cell(&self.x_range, x, width);
+        let (row, v) = cell(&self.y_range, y, height);
+        let next_column = (column + 1).min(width - 1);
+        let next_row = (row + 1).min(height - 1);
+
+        let corner = |column: usize, row: usize| self.value_sets[row * width + column].clone();
+        let top = lerp(corner(column, row), corner(next_column, row), u);
+        let bottom = lerp(corner(column, next_row), corner(next_column, next_row), u);
+        lerp(top, bottom, v)
+    }
+}
+
+/// Locate `s` along an axis of `count` evenly spaced value sets.  
+/// Returns the index of the value set before `s`, and how far `s` is towards the next one, in `0.0..=1.0`
+fn cell<S: Numeric>(range: &ReversibleRange<S>, s: S, count: usize) -> (usize, f64) {
+    let last = count - 1;
+    if last == 0 || range.is_empty() {
+        return (0, 0.0);
+    }
+
+    let position = range.inverse_lerp(s) * last.into_f64();
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let index = (position as usize).min(last - 1);
+    (index, position - index.into_f64())
+}
+
+/// Blend between two value sets, `t` of the way from `lo` to `hi`
+fn lerp<const N: usize, T: Numeric>(lo: [T; N], hi: [T; N], t: f64) -> [T; N] {
+    InterpolationBucket::new((0.0, 1.0), lo, hi).interpolate(t)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_grid_interpolate() {
+        let grid = GridInterpolator::new(
+            0.0..=2.0,
+            10.0..=0.0,
+            &[[[0.0], [10.0], [20.0]], [[100.0], [110.0], [120.0]]],
+        );
+        assert_eq!(grid.dimensions(), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&[120.0]));
+        assert_eq!(grid.get(3, 0), None);
+
+        // Corners, with the y axis reversed
+        assert_eq!(grid.interpolate(0.0, 10.0), [0.0]);
+        assert_eq!(grid.interpolate(2.0, 10.0), [20.0]);
+        assert_eq!(grid.interpolate(0.0, 0.0), [100.0]);
+        assert_eq!(grid.interpolate(2.0, 0.0), [120.0]);
+
+        // Interior points blend all four corners of their cell
+        assert_eq!(grid.interpolate(0.5, 5.0), [55.0]);
+        assert_eq!(grid.interpolate(1.5, 7.5), [40.0]);
+
+        // Out of range inputs are clamped per axis
+        assert_eq!(grid.interpolate(-5.0, 20.0), [0.0]);
+        assert_eq!(grid.interpolate(5.0, 5.0), [70.0]);
+    }
+
+    #[test]
+    fn test_grid_integer() {
+        let grid: GridInterpolator<2, u8, u8> = GridInterpolator::new(
+            0..=100,
+            0..=100,
+            &[[[0, 255], [255, 255]], [[0, 0], [255, 0]]],
+        );
+        assert_eq!(grid.interpolate(50, 50), [127, 128]);
+        assert_eq!(grid.interpolate(100, 100), [255, 0]);
+
+        // Degenerate grids
+        let row: GridInterpolator<1, u8, u8> =
+            GridInterpolator::new(0..=10, 0..=10, &[[[0], [100]]]);
+        assert_eq!(row.interpolate(5, 7), [50]);
+        let empty: GridInterpolator<1, u8, u8> = GridInterpolator::new::<0>(0..=10, 0..=10, &[]);
+        assert_eq!(empty.interpolate(5, 5), [0]);
+    }
+}
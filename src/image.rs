@@ -0,0 +1,65 @@
+//! Loading of interpolators from rows of pixels in an `image` crate `RgbImage`.
+use crate::LinearInterpolator;
+use ::image::RgbImage;
+
+impl LinearInterpolator<'_, 3, f64, u8> {
+    /// Create an RGB interpolator over `0.0..=1.0` by picking `stops` evenly spaced pixels from one row of an image,
+    /// including the first and last. This can be used to load a gradient authored in an image editor.
+    ///
+    /// See [`LinearInterpolator::from_samples`] for details.
+    ///
+    /// Returns None if:
+    /// - `row` is outside of the image
+    /// - `stops < 2`, or `stops` is greater than the width of the image
+    ///
+    /// # Example
+    /// ```rust
+    /// use image::{Rgb, RgbImage};
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let image = RgbImage::from_fn(101, 1, |x, _| Rgb([x as u8, 0, 100 - x as u8]));
+    /// let interpolator = LinearInterpolator::from_image_row(&image, 0, 3).unwrap();
+    /// assert_eq!(interpolator.interpolate(0.5), [50, 0, 50]);
+    /// ```
+    #[must_use]
+    pub fn from_image_row(image: &RgbImage, row: u32, stops: usize) -> Option<Self> {
+        let samples: Vec<[u8; 3]> = image
+            .rows()
+            .nth(row as usize)?
+            .map(|pixel| pixel.0)
+            .collect();
+        Self::from_samples(0.0..=1.0, &samples, stops)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::image::Rgb;
+
+    #[test]
+    fn test_from_image_row() {
+        let image = RgbImage::from_fn(5, 2, |x, y| {
+            let x = u8::try_from(x).unwrap();
+            if y == 0 {
+                Rgb([x * 50, 0, 200 - x * 50])
+            } else {
+                Rgb([0, x, 0])
+            }
+        });
+
+        let interpolator = LinearInterpolator::from_image_row(&image, 0, 3).unwrap();
+        assert_eq!(
+            interpolator.value_sets(),
+            [[0, 0, 200], [100, 0, 100], [200, 0, 0]]
+        );
+        assert_eq!(interpolator.interpolate(0.25), [50, 0, 150]);
+
+        let interpolator = LinearInterpolator::from_image_row(&image, 1, 5).unwrap();
+        assert_eq!(interpolator.value_sets()[4], [0, 4, 0]);
+
+        assert!(LinearInterpolator::from_image_row(&image, 2, 3).is_none());
+        assert!(LinearInterpolator::from_image_row(&image, 0, 6).is_none());
+        assert!(LinearInterpolator::from_image_row(&image, 0, 1).is_none());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{InterpolationBucket, Numeric};
+
+/// A common interface for anything that maps an input `S` onto `N` values of type `T`.
+///
+/// The trait is object-safe, so interpolators of different kinds can be stored together, such as in a `Vec<Box<dyn Interpolate<N, S, T>>>`.  
+/// Each implementor also keeps its inherent `interpolate` method, so the trait does not need to be in scope for normal use.
+///
+/// # Example
+/// ```rust
+/// use lineic::{CachedInterpolator, Interpolate, LinearInterpolator};
+///
+/// let linear = LinearInterpolator::new(0.0..=10.0, &[[0.0f32, 0.0, 0.0], [10.0, 20.0, 30.0]]);
+/// let cached = CachedInterpolator::new(linear.clone());
+///
+/// let interpolators: Vec<Box<dyn Interpolate<3, f32, f32>>> = vec![Box::new(linear), Box::new(cached)];
+/// for interpolator in &interpolators {
+///     assert_eq!(interpolator.interpolate(5.0), [5.0, 10.0, 15.0]);
+/// }
+/// ```
+pub trait Interpolate<const N: usize, S, T> {
+    /// Interpolate between the value sets based on the given value
+    fn interpolate(&self, s: S) -> [T; N];
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> Interpolate<N, S, T> for InterpolationBucket<N, S, T> {
+    fn interpolate(&self, s: S) -> [T; N] {
+        InterpolationBucket::interpolate(self, s)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod test {
+    use super::*;
+    use crate::{CachedInterpolator, LinearInterpolator, TableInterpolator, TaggedInterpolator};
+
+    #[test]
+    fn test_interpolate_dyn() {
+        let linear = LinearInterpolator::new((0u8, 10), &[[0u8, 100], [100, 0]]);
+        let interpolators: Vec<Box<dyn Interpolate<2, u8, u8>>> = vec![
+            Box::new(linear.buckets()[0].clone()),
+            Box::new(CachedInterpolator::new(linear.clone())),
+            Box::new(TableInterpolator::from(&linear)),
+            Box::new(TaggedInterpolator::new(linear.clone(), ["only"])),
+            Box::new(linear.clone()),
+        ];
+
+        for interpolator in &interpolators {
+            for s in [0, 3, 5, 10, 20] {
+                assert_eq!(interpolator.interpolate(s), linear.interpolate(s));
+            }
+        }
+    }
+}
@@ -1,6 +1,41 @@
-use crate::{number::Numeric, InterpolationBucket, ReversibleRange};
+use crate::{
+    number::{saturate, saturating_offset, saturating_scale, Numeric},
+    ChannelMode, Easing, Interpolate, InterpolationBucket, InterpolatorError, OutOfDomain,
+    ReversibleRange,
+};
 use std::borrow::Cow;
 
+/// Controls how [`LinearInterpolator::interpolate`] handles inputs outside of the interpolator's range.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum OutOfRange {
+    /// Inputs are clamped to the range, so the values at its ends are returned
+    #[default]
+    Clamp,
+
+    /// Panics in debug builds, to catch unnormalized inputs early.  
+    /// In release builds this behaves like [`OutOfRange::Clamp`].
+    Panic,
+
+    /// The nearest bucket is extended past the end of the range, saturating at the bounds of `T`
+    Saturate,
+}
+
+/// The direction a channel moves in across the range of a [`LinearInterpolator`], from its start to its end.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Monotonicity {
+    /// The channel never decreases, and increases at least once
+    Increasing,
+
+    /// The channel never increases, and decreases at least once
+    Decreasing,
+
+    /// The channel has the same value across the whole range
+    Constant,
+
+    /// The channel both increases and decreases, or has values that cannot be compared
+    NonMonotonic,
+}
+
 /// A linear interpolator for a set of values.  
 /// Interpolates between a series of discrete value sets based on a range.
 ///
@@ -32,6 +67,9 @@ use std::borrow::Cow;
 #[derive(Debug, PartialEq, Clone)]
 pub struct LinearInterpolator<'a, const N: usize, S: Numeric, T: Numeric> {
     buckets: Cow<'a, [InterpolationBucket<N, S, T>]>,
+    output_clamp: Option<([T; N], [T; N])>,
+    channel_modes: Option<[ChannelMode; N]>,
+    out_of_range: OutOfRange,
 }
 impl<'a, const N: usize, S: Numeric, T: Numeric> LinearInterpolator<'a, N, S, T> {
     /// Create a new linear interpolator with the given range and value sets.  
@@ -54,50 +92,318 @@ impl<'a, const N: usize, S: Numeric, T: Numeric> LinearInterpolator<'a, N, S, T>
         let range = range.into();
 
         if value_sets.is_empty() {
-            let buckets = Cow::Owned(vec![InterpolationBucket::new(
-                range,
-                [T::ZERO; N],
-                [T::ZERO; N],
-            )]);
-            return Some(Self { buckets });
+            let buckets = vec![InterpolationBucket::new(range, [T::ZERO; N], [T::ZERO; N])];
+            return Some(Self::from_buckets(buckets));
         }
 
-        let capacity = value_sets.len() - 1;
-        let mut buckets = Vec::with_capacity(capacity);
-
         // Noop interpolation
-        if capacity == 0 {
-            let values = value_sets[0];
-            buckets.push(InterpolationBucket::new(range, values, values));
-            let buckets = Cow::Owned(buckets);
-            return Some(Self { buckets });
+        if let [values] = value_sets {
+            let bucket = InterpolationBucket::new(range, values.clone(), values.clone());
+            return Some(Self::from_buckets(vec![bucket]));
         }
 
-        let len = range.start.abs_diff(range.end);
-        let step_by = len.checked_div(S::from_usize(capacity)?)?;
+        let buckets = range
+            .split(value_sets.len() - 1)?
+            .zip(value_sets.windows(2))
+            .map(|(bucket_range, v)| {
+                InterpolationBucket::new(bucket_range, v[0].clone(), v[1].clone())
+            })
+            .collect();
 
-        let mut start = range.start;
-        for i in 0..capacity {
-            let is_last = i == value_sets.len() - 2;
+        Some(Self::from_buckets(buckets))
+    }
 
-            let end = if is_last {
-                range.end
-            } else if range.is_reversed() {
-                start.checked_sub(step_by).unwrap_or(S::ZERO)
-            } else {
-                start.checked_add(step_by).unwrap_or(S::MAX)
-            };
-            let range = start..=end;
+    /// Create a new linear interpolator with the given range, and value sets taken from an iterator.  
+    /// The provided range will be divided into equal segments based on the number of value sets.
+    ///
+    /// This is the same as [`Self::try_new`], but accepts the value sets lazily, such as from a chain of iterator adapters.
+    ///
+    /// Returns None if the number of value sets is too large to be represented by type S.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let squares = (0..=4).map(|i| [f64::from(i * i)]);
+    /// let interpolator = LinearInterpolator::from_iter_stops(0.0..=4.0, squares).unwrap();
+    /// assert_eq!(interpolator.interpolate(2.5), [6.5]);
+    /// ```
+    pub fn from_iter_stops<I: IntoIterator<Item = [T; N]>>(
+        range: impl Into<ReversibleRange<S>>,
+        stops: I,
+    ) -> Option<Self> {
+        let value_sets: Vec<_> = stops.into_iter().collect();
+        Self::try_new(range, &value_sets)
+    }
+
+    /// Create a new linear interpolator with the given range and value sets, checking the length of each value set at runtime.  
+    /// The provided range will be divided into equal segments based on the number of value sets.
+    ///
+    /// This is the same as [`Self::try_new`], but accepts rows of any length, such as parsed or deserialized data,
+    /// without the caller having to convert them into arrays first.
+    ///
+    /// # Errors
+    /// - [`InterpolatorError::ValueSetLength`] if a row does not have exactly `N` values
+    /// - [`InterpolatorError::TooManyValueSets`] if the number of value sets is too large to be represented by type S
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{InterpolatorError, LinearInterpolator};
+    ///
+    /// let rows: Vec<Vec<f64>> = vec![vec![0.0, 0.0], vec![10.0, 20.0]];
+    /// let rows: Vec<&[f64]> = rows.iter().map(Vec::as_slice).collect();
+    /// let interpolator = LinearInterpolator::<2, _, _>::try_new_checked(0.0..=1.0, &rows).unwrap();
+    /// assert_eq!(interpolator.interpolate(0.5), [5.0, 10.0]);
+    ///
+    /// let error = LinearInterpolator::<3, f64, f64>::try_new_checked(0.0..=1.0, &rows).unwrap_err();
+    /// assert_eq!(error, InterpolatorError::ValueSetLength { index: 0, len: 2, expected: 3 });
+    /// ```
+    pub fn try_new_checked(
+        range: impl Into<ReversibleRange<S>>,
+        rows: &[&[T]],
+    ) -> Result<Self, InterpolatorError> {
+        let value_sets = rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                <&[T; N]>::try_from(*row)
+                    .cloned()
+                    .map_err(|_| InterpolatorError::ValueSetLength {
+                        index,
+                        len: row.len(),
+                        expected: N,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::try_new(range, &value_sets).ok_or(InterpolatorError::TooManyValueSets)
+    }
+
+    /// Create a new linear interpolator with the given range and value sets, and a mode for each channel.  
+    /// The provided range will be divided into equal segments based on the number of value sets.
+    ///
+    /// Channels set to [`ChannelMode::Step`] snap between stops at the midpoint of each bucket instead of blending.  
+    /// This allows smooth and stepped channels to be mixed in one interpolator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{ChannelMode, LinearInterpolator};
+    ///
+    /// // A color blend, alongside an icon index that steps between stops
+    /// let interpolator = LinearInterpolator::new_with_modes(
+    ///     0.0..=10.0,
+    ///     &[[255.0, 0.0, 0.0], [0.0, 255.0, 1.0]],
+    ///     [ChannelMode::Continuous, ChannelMode::Continuous, ChannelMode::Step],
+    /// );
+    ///
+    /// assert_eq!(interpolator.interpolate(4.0), [153.0, 102.0, 0.0]);
+    /// assert_eq!(interpolator.interpolate(6.0), [102.0, 153.0, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the number of value sets is too large to be represented by type S
+    pub fn new_with_modes(
+        range: impl Into<ReversibleRange<S>>,
+        value_sets: &[[T; N]],
+        modes: [ChannelMode; N],
+    ) -> Self {
+        let mut interpolator = Self::new(range, value_sets);
+        interpolator.channel_modes = Some(modes);
+        interpolator
+    }
+
+    /// Create a new linear interpolator from parallel slices of stop positions and value sets.  
+    /// Each value set is placed at the matching position, and a bucket is created between each pair of stops.
+    ///
+    /// Positions may be in ascending order, or descending order for a reversed range.
+    ///
+    /// Returns None if:
+    /// - `positions` and `value_sets` are empty, or have different lengths
+    /// - `positions` are not monotonic (ascending or descending)
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let positions = [0.0, 10.0, 100.0];
+    /// let value_sets = [[0.0], [50.0], [100.0]];
+    ///
+    /// let interpolator = LinearInterpolator::from_soa(&positions, &value_sets).unwrap();
+    /// assert_eq!(interpolator.interpolate(5.0), [25.0]);
+    /// assert_eq!(interpolator.interpolate(55.0), [75.0]);
+    /// ```
+    pub fn from_soa(positions: &[S], value_sets: &[[T; N]]) -> Option<Self> {
+        if positions.is_empty() || positions.len() != value_sets.len() {
+            return None;
+        }
+
+        let reversed = positions.first() > positions.last();
+        let is_monotonic =
+            positions
+                .windows(2)
+                .all(|p| if reversed { p[0] >= p[1] } else { p[0] <= p[1] });
+        if !is_monotonic {
+            return None;
+        }
+
+        Some(Self::from_stops(positions, value_sets))
+    }
+
+    /// Create a new linear interpolator from animation keyframes, each given as `(values, duration_to_next)`.  
+    /// The first frame is placed at `start_time`, and each following frame after the previous one's duration;
+    /// the last frame's duration is ignored, since it is the end of the timeline.
+    ///
+    /// Returns None if:
+    /// - `frames` is empty
+    /// - The end of the timeline is too large to be represented by type S
+    /// - The durations mix positive and negative values, so the timeline is not monotonic
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let fade = LinearInterpolator::from_keyframes(0.0, &[([0.0], 1.0), ([1.0], 3.0), ([0.0], 0.0)]).unwrap();
+    /// assert_eq!(fade.interpolate(0.5), [0.5]);
+    /// assert_eq!(fade.interpolate(2.5), [0.5]);
+    /// assert_eq!(fade.interpolate(4.0), [0.0]);
+    /// ```
+    pub fn from_keyframes(start_time: S, frames: &[([T; N], S)]) -> Option<Self> {
+        let (_, leading) = frames.split_last()?;
+        let mut positions = Vec::with_capacity(frames.len());
+        let mut time = start_time;
+        for (_, duration) in leading {
+            positions.push(time.clone());
+            time = time.checked_add(duration.clone())?;
+        }
+        positions.push(time);
+
+        let value_sets: Vec<_> = frames.iter().map(|(values, _)| values.clone()).collect();
+        Self::from_soa(&positions, &value_sets)
+    }
+
+    /// Create a new linear interpolator from a cumulative distribution.  
+    /// Each value set is placed at the position given by the matching entry in `cdf`, mapped onto the range.
+    ///
+    /// `cdf` contains normalized positions (`0.0` is the start of the range, `1.0` the end) and must be
+    /// monotonically non-decreasing. This can be used to build a histogram-equalization curve.
+    ///
+    /// Returns None if:
+    /// - `cdf` and `value_sets` are empty, or have different lengths
+    /// - Any entry of `cdf` is outside of `0.0..=1.0`, or smaller than the entry before it
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::from_cdf(0.0..=100.0, &[0.0, 0.8, 1.0], &[[0.0], [128.0], [255.0]]).unwrap();
+    /// assert_eq!(interpolator.interpolate(80.0), [128.0]);
+    /// ```
+    pub fn from_cdf(
+        range: impl Into<ReversibleRange<S>>,
+        cdf: &[f64],
+        value_sets: &[[T; N]],
+    ) -> Option<Self> {
+        let range = range.into();
+        if cdf.is_empty() || cdf.len() != value_sets.len() {
+            return None;
+        }
+
+        let mut previous = 0.0;
+        for &position in cdf {
+            if !(previous..=1.0).contains(&position) {
+                return None;
+            }
+            previous = position;
+        }
+
+        let positions: Vec<_> = cdf.iter().map(|f| range.lerp(*f)).collect();
+        Some(Self::from_stops(&positions, value_sets))
+    }
+
+    /// Create a new linear interpolator by picking `stops` evenly spaced value sets from a longer run of samples,
+    /// including the first and last. The provided range will be divided into equal segments between them.
+    ///
+    /// This can be used to load a gradient authored in an image editor, from one row of pixels;
+    /// with the `image` feature, `LinearInterpolator::from_image_row` does this for an `image::RgbImage`.
+    ///
+    /// Returns None if:
+    /// - `stops < 2`, or `stops` is greater than the number of samples
+    /// - The number of stops is too large to be represented by type S
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let row: Vec<[u8; 3]> = (0..=100).map(|x| [x, 0, 100 - x]).collect();
+    /// let interpolator = LinearInterpolator::from_samples(0.0..=1.0, &row, 3).unwrap();
+    /// assert_eq!(interpolator.value_sets(), [[0, 0, 100], [50, 0, 50], [100, 0, 0]]);
+    /// ```
+    pub fn from_samples(
+        range: impl Into<ReversibleRange<S>>,
+        samples: &[[T; N]],
+        stops: usize,
+    ) -> Option<Self> {
+        if stops < 2 || stops > samples.len() {
+            return None;
+        }
+
+        let last = samples.len() - 1;
+        let value_sets: Vec<_> = (0..stops)
+            .map(|i| samples[(i * last + (stops - 1) / 2) / (stops - 1)].clone())
+            .collect();
+        Self::try_new(range, &value_sets)
+    }
 
-            let values_lo = value_sets[i];
-            let values_hi = value_sets[i + 1];
+    /// Create a new linear interpolator with the given range and value sets, where each stop holds its value for a while.  
+    /// The provided range will be divided into equal segments based on the number of value sets.
+    ///
+    /// `hold_fraction` of each segment is spent holding a stop's value - half at each end of the segment - and
+    /// the transition between stops is compressed into the remaining span. This produces ease-hold-ease timelines.
+    ///
+    /// Returns None if `hold_fraction` is not in `0.0..1.0`
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new_with_holds(0.0..=100.0, &[[0.0], [100.0]], 0.5).unwrap();
+    /// assert_eq!(interpolator.interpolate(10.0), [0.0]);
+    /// assert_eq!(interpolator.interpolate(50.0), [50.0]);
+    /// assert_eq!(interpolator.interpolate(90.0), [100.0]);
+    /// ```
+    pub fn new_with_holds(
+        range: impl Into<ReversibleRange<S>>,
+        value_sets: &[[T; N]],
+        hold_fraction: f64,
+    ) -> Option<Self> {
+        let range = range.into();
+        if !(0.0..1.0).contains(&hold_fraction) {
+            return None;
+        }
+
+        if value_sets.len() < 2 {
+            return Self::try_new(range, value_sets);
+        }
 
-            buckets.push(InterpolationBucket::new(range, values_lo, values_hi));
-            start = end;
+        // Each stop gets a flat bucket spanning half a hold on either side of it, clipped to the range
+        let segments = (value_sets.len() - 1).into_f64();
+        let half_hold = hold_fraction / 2.0;
+        let mut positions = Vec::with_capacity(value_sets.len() * 2);
+        let mut stops = Vec::with_capacity(value_sets.len() * 2);
+        for (i, values) in value_sets.iter().enumerate() {
+            let center = i.into_f64();
+            let lo = ((center - half_hold) / segments).max(0.0);
+            let hi = ((center + half_hold) / segments).min(1.0);
+
+            positions.push(range.lerp(lo));
+            stops.push(values.clone());
+            if hi > lo {
+                positions.push(range.lerp(hi));
+                stops.push(values.clone());
+            }
         }
 
-        let buckets = Cow::Owned(buckets);
-        Some(Self { buckets })
+        Some(Self::from_stops(&positions, &stops))
     }
 
     /// Create a new linear interpolator from a raw slice of buckets.
@@ -121,7 +427,100 @@ impl<'a, const N: usize, S: Numeric, T: Numeric> LinearInterpolator<'a, N, S, T>
     /// - The buckets must be sorted by range  
     pub const fn new_from_raw(buckets: &'a [InterpolationBucket<N, S, T>]) -> Self {
         let buckets = Cow::Borrowed(buckets);
-        Self { buckets }
+        Self {
+            buckets,
+            output_clamp: None,
+            channel_modes: None,
+            out_of_range: OutOfRange::Clamp,
+        }
+    }
+
+    /// Wrap a set of owned buckets in an interpolator with default settings
+    pub(crate) fn from_buckets(buckets: Vec<InterpolationBucket<N, S, T>>) -> Self {
+        Self {
+            buckets: Cow::Owned(buckets),
+            output_clamp: None,
+            channel_modes: None,
+            out_of_range: OutOfRange::Clamp,
+        }
+    }
+
+    /// Clamp the interpolated values to the given per-channel bounds.  
+    /// The interpolation itself is unchanged; the bounds are applied to the result.
+    ///
+    /// This allows the stop values to exceed the legal output range (for overshoot),
+    /// while still guaranteeing the returned values fall within `min..=max`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[-100.0], [300.0]])
+    ///     .with_output_clamp([0.0], [255.0]);
+    ///
+    /// assert_eq!(interpolator.interpolate(0.0), [0.0]);
+    /// assert_eq!(interpolator.interpolate(5.0), [100.0]);
+    /// assert_eq!(interpolator.interpolate(10.0), [255.0]);
+    /// ```
+    #[must_use]
+    pub fn with_output_clamp(mut self, min: [T; N], max: [T; N]) -> Self {
+        self.output_clamp = Some((min, max));
+        self
+    }
+
+    /// Get the per-channel bounds applied to interpolated values, if any.
+    #[must_use]
+    pub fn output_clamp(&self) -> Option<&([T; N], [T; N])> {
+        self.output_clamp.as_ref()
+    }
+
+    /// Set how [`Self::interpolate`] handles inputs outside of the range; see [`OutOfRange`].  
+    /// The default is [`OutOfRange::Clamp`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{LinearInterpolator, OutOfRange};
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0u8], [100]]);
+    /// assert_eq!(interpolator.interpolate(15.0), [100]);
+    ///
+    /// let interpolator = interpolator.with_out_of_range(OutOfRange::Saturate);
+    /// assert_eq!(interpolator.interpolate(15.0), [150]);
+    /// assert_eq!(interpolator.interpolate(50.0), [255]);
+    /// assert_eq!(interpolator.interpolate(-5.0), [0]);
+    /// ```
+    #[must_use]
+    pub fn with_out_of_range(mut self, policy: OutOfRange) -> Self {
+        self.out_of_range = policy;
+        self
+    }
+
+    /// Get how [`Self::interpolate`] handles inputs outside of the range.
+    #[must_use]
+    pub fn out_of_range(&self) -> OutOfRange {
+        self.out_of_range
+    }
+
+    /// Get the per-channel interpolation modes, if any were set.  
+    /// If None, all channels are [`ChannelMode::Continuous`].
+    #[must_use]
+    pub fn channel_modes(&self) -> Option<&[ChannelMode; N]> {
+        self.channel_modes.as_ref()
+    }
+
+    /// Apply the output clamp, if any, to a set of interpolated values
+    fn clamp_output(&self, mut values: [T; N]) -> [T; N] {
+        self.clamp_output_in_place(&mut values);
+        values
+    }
+
+    /// Clamp the given values in place, if an output clamp is set
+    fn clamp_output_in_place(&self, values: &mut [T; N]) {
+        if let Some((min, max)) = &self.output_clamp {
+            for (i, value) in values.iter_mut().enumerate() {
+                *value = value.clone().clamp(min[i].clone(), max[i].clone());
+            }
+        }
     }
 
     /// Returns true if the range for this interpolator has start > end
@@ -132,59 +531,2045 @@ impl<'a, const N: usize, S: Numeric, T: Numeric> LinearInterpolator<'a, N, S, T>
             .is_some_and(|b| b.range().is_reversed())
     }
 
+    /// Get how far `s` is through the whole range of this interpolator, as a value in `0.0..=1.0`.  
+    /// Values outside of the range are clamped, and reversed ranges still report `0.0` at their start.
+    ///
+    /// Unlike [`InterpolationBucket::progress`], this covers every bucket at once; useful for progress bars.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(100.0..=0.0, &[[0.0], [5.0], [10.0]]);
+    /// assert_eq!(interpolator.domain_progress(75.0), 0.25);
+    /// assert_eq!(interpolator.domain_progress(-10.0), 1.0);
+    /// ```
+    pub fn domain_progress(&self, s: S) -> f64 {
+        self.domain().inverse_lerp(s)
+    }
+
+    /// Check that this interpolator's buckets form a single continuous range.  
+    /// This always holds for interpolators created by the constructors, but is not enforced by [`Self::new_from_raw`].
+    ///
+    /// # Errors
+    /// Returns the first violation found, in bucket order:
+    /// - [`InterpolatorError::NoBuckets`] if there are no buckets at all
+    /// - [`InterpolatorError::Misoriented`] if a bucket runs in the opposite direction to the range as a whole
+    /// - [`InterpolatorError::Discontinuous`] if a bucket does not end where the next one starts
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{InterpolationBucket, InterpolatorError, LinearInterpolator};
+    ///
+    /// let buckets = [
+    ///     InterpolationBucket::from_tuple((0.0, 50.0), [0.0], [1.0]),
+    ///     InterpolationBucket::from_tuple((60.0, 100.0), [1.0], [2.0]),
+    /// ];
+    /// let interpolator = LinearInterpolator::new_from_raw(&buckets);
+    /// assert_eq!(interpolator.validate(), Err(InterpolatorError::Discontinuous { index: 0 }));
+    /// ```
+    pub fn validate(&self) -> Result<(), InterpolatorError> {
+        let buckets = self.buckets();
+        if buckets.is_empty() {
+            return Err(InterpolatorError::NoBuckets);
+        }
+
+        let reversed = self.domain().is_reversed();
+        for (index, bucket) in buckets.iter().enumerate() {
+            if !bucket.range().is_empty() && bucket.range().is_reversed() != reversed {
+                return Err(InterpolatorError::Misoriented { index });
+            }
+
+            if let Some(next) = buckets.get(index + 1) {
+                if bucket.end() != next.start() {
+                    return Err(InterpolatorError::Discontinuous { index });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the set of discrete interpolations this interpolator will use.
     #[must_use]
     pub fn buckets(&self) -> &[InterpolationBucket<N, S, T>] {
         &self.buckets
     }
 
+    /// Get mutable access to this interpolator's buckets, for editing them in place.  
+    /// If the buckets are borrowed, such as from [`static_interpolator!`], they are copied first.
+    ///
+    /// **Warning:** changing a bucket's range can break the contiguity of the interpolator;
+    /// use [`Self::validate`] to check it afterwards.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let mut gradient = LinearInterpolator::new(0.0..=10.0, &[[0.0], [10.0], [20.0]]);
+    /// for bucket in gradient.buckets_mut() {
+    ///     bucket.values_hi_mut()[0] *= 2.0;
+    /// }
+    /// assert_eq!(gradient.value_sets(), vec![[0.0], [20.0], [40.0]]);
+    /// ```
+    pub fn buckets_mut(&mut self) -> &mut [InterpolationBucket<N, S, T>] {
+        self.buckets.to_mut()
+    }
+
+    /// Returns a mutable reference to the bucket that contains the given value, or None if there are no buckets.  
+    /// If the buckets are borrowed, such as from [`static_interpolator!`], they are copied first.
+    ///
+    /// **Warning:** changing a bucket's range can break the contiguity of the interpolator;
+    /// use [`Self::validate`] to check it afterwards.  
+    /// Editing the values at a shared stop only affects one side of it; update the neighbouring bucket to keep the gradient continuous.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let mut gradient = LinearInterpolator::new(0.0..=10.0, &[[0.0], [10.0], [20.0]]);
+    /// if let Some(bucket) = gradient.get_bucket_mut(2.5) {
+    ///     *bucket.values_lo_mut() = [4.0];
+    /// }
+    /// assert_eq!(gradient.interpolate(2.5), [7.0]);
+    /// ```
+    pub fn get_bucket_mut(&mut self, s: S) -> Option<&mut InterpolationBucket<N, S, T>> {
+        let index = self.bucket_index(s);
+        self.buckets_mut().get_mut(index)
+    }
+
+    /// Create a new interpolator covering only the bucket at `index`, keeping this interpolator's settings.  
+    /// Useful for zooming in on a single segment, such as to render a detail view of part of a gradient.
+    ///
+    /// Returns None if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0], [10.0], [0.0]]);
+    /// let segment = interpolator.bucket_as_interpolator(1).unwrap();
+    /// assert_eq!(segment.buckets(), &interpolator.buckets()[1..]);
+    /// assert_eq!(segment.interpolate(0.0), [10.0]);
+    /// ```
+    #[must_use]
+    pub fn bucket_as_interpolator(&self, index: usize) -> Option<Self> {
+        let bucket = self.buckets().get(index)?.clone();
+        Some(Self::from_buckets(vec![bucket]).with_settings_from(self))
+    }
+
+    /// Convert this interpolator into one that owns its buckets, cloning them if they were borrowed.  
+    /// Useful for storing an interpolator created with [`Self::new_from_raw`] long-term.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{InterpolationBucket, LinearInterpolator};
+    ///
+    /// fn load() -> LinearInterpolator<'static, 1, f64, f64> {
+    ///     let buckets = vec![InterpolationBucket::new(0.0..=1.0, [0.0], [10.0])];
+    ///     LinearInterpolator::new_from_raw(&buckets).into_owned()
+    /// }
+    ///
+    /// assert_eq!(load().interpolate(0.5), [5.0]);
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> LinearInterpolator<'static, N, S, T>
+    where
+        S: 'static,
+        T: 'static,
+    {
+        LinearInterpolator {
+            buckets: Cow::Owned(self.buckets.into_owned()),
+            output_clamp: self.output_clamp,
+            channel_modes: self.channel_modes,
+            out_of_range: self.out_of_range,
+        }
+    }
+
+    /// Reconstruct the list of value sets this interpolator was built from.  
+    /// This is the first bucket's `values_lo`, followed by each bucket's `values_hi`.
+    ///
+    /// An interpolator built from a single value set has one bucket spanning from that set to itself,
+    /// so the set will be returned twice.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let value_sets = [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]];
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &value_sets);
+    /// assert_eq!(interpolator.value_sets(), value_sets);
+    /// ```
+    #[must_use]
+    pub fn value_sets(&self) -> Vec<[T; N]> {
+        self.stops_iter()
+            .map(|(_, values)| values.clone())
+            .collect()
+    }
+
+    /// Get the position of each stop as a fraction of the range, from `0.0` at its start to `1.0` at its end.  
+    /// Stops are in the same order as [`Self::value_sets`], so the two can be zipped into gradient stop offsets and colors.
+    ///
+    /// Reversed ranges still start at `0.0`, so the offsets always ascend from the first stop to the last.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::from_soa(&[200.0, 150.0, 100.0], &[[0], [5], [10]]).unwrap();
+    /// assert_eq!(interpolator.normalized_stops(), vec![0.0, 0.5, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn normalized_stops(&self) -> Vec<f64> {
+        self.stops_iter()
+            .map(|(s, _)| self.domain_progress(s))
+            .collect()
+    }
+
+    /// Get the value set at the start of the range; the first bucket's `values_lo`.  
+    /// Unlike `interpolate(start)`, this is exact and does not apply the output clamp.
+    #[must_use]
+    pub fn first_value(&self) -> [T; N] {
+        self.buckets()
+            .first()
+            .map_or([T::ZERO; N], |b| b.values_lo().clone())
+    }
+
+    /// Get the value set at the end of the range; the last bucket's `values_hi`.  
+    /// Unlike `interpolate(end)`, this is exact and does not apply the output clamp.
+    #[must_use]
+    pub fn last_value(&self) -> [T; N] {
+        self.buckets()
+            .last()
+            .map_or([T::ZERO; N], |b| b.values_hi().clone())
+    }
+
     /// Returns the bucket that contains the given value.
     pub fn get_bucket(&self, s: S) -> &InterpolationBucket<N, S, T> {
+        &self.buckets()[self.bucket_index(s)]
+    }
+
+    /// Returns the indices of the 2 stops bracketing `s`, and how far `s` is from the first towards the second.  
+    /// The indices refer to positions in [`Self::value_sets`], so only the stops need to be uploaded to a GPU
+    /// for the shader to perform the blend itself: `mix(stops[lo], stops[hi], weight)`.
+    ///
+    /// `lo` is always the stop nearer the start of the range, so for reversed ranges it holds the larger input.  
+    /// The weight is clamped to `0.0..=1.0`; channel modes and the output clamp are left to the caller.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(100.0..=0.0, &[[0.0], [5.0], [10.0]]);
+    /// assert_eq!(interpolator.blend_indices(75.0), (0, 1, 0.5));
+    /// assert_eq!(interpolator.blend_indices(40.0), (1, 2, 0.2));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn blend_indices(&self, s: S) -> (usize, usize, f32) {
+        let index = self.bucket_index(s.clone());
+        let weight = self.buckets()[index].progress(s) as f32;
+        (index, index + 1, weight)
+    }
+
+    /// Returns the index of the bucket that contains the given value.
+    #[allow(clippy::needless_pass_by_value)] // Inputs are taken by value throughout the API, as they are usually Copy
+    pub(crate) fn bucket_index(&self, s: S) -> usize {
         let rev = self.is_reversed();
         let mut slice = self.buckets();
+        let mut offset = 0;
 
         // Binary search for the bucket that contains the value
         while slice.len() > 1 {
             let mid = slice.len() / 2;
             let mid_bucket = &slice[mid];
 
-            if mid_bucket.range().contains(s) {
-                return mid_bucket;
+            if mid_bucket.range().contains(s.clone()) {
+                return offset + mid;
             }
 
-            if (!rev && s >= mid_bucket.start()) || (rev && s <= mid_bucket.start()) {
+            let start = &mid_bucket.range().start;
+            if (!rev && s >= *start) || (rev && s <= *start) {
                 slice = &slice[mid..];
+                offset += mid;
             } else {
                 slice = &slice[..mid];
             }
         }
 
-        &slice[0]
+        offset
     }
 
     /// Interpolate between the value sets based on the given value.  
     /// This will return a new set of values interpolated across the given range
     ///
     /// Uses a binary search to locate the appropriate pair of values to interpolate between
+    ///
+    /// # Panics
+    /// In debug builds, panics if `s` is outside of the range and the policy is [`OutOfRange::Panic`]
     pub fn interpolate(&self, s: S) -> [T; N] {
-        let bucket = self.get_bucket(s);
-        bucket.interpolate(s)
+        self.interpolate_in(self.get_bucket(s.clone()), s)
     }
 
-    /// Attempt to find a value in the valid range that could produce the given set of values.
+    /// Interpolate between the value sets based on the given value, writing the result into `out`.  
+    /// Produces the same values as [`Self::interpolate`], without returning them by value.
     ///
-    /// This may be slow, since all buckets may be checked
-    pub fn reverse_interpolate(&self, values: &[T; N]) -> Option<S> {
-        for bucket in self.buckets() {
-            if let Some(s) = bucket.reverse_interpolate(values) {
-                return Some(s);
-            }
-        }
-
-        None
-    }
-}
-
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0, 100.0], [100.0, 0.0]]);
+    /// let mut out = [0.0; 2];
+    /// interpolator.interpolate_into(2.5, &mut out);
+    /// assert_eq!(out, [25.0, 75.0]);
+    /// ```
+    ///
+    /// # Panics
+    /// In debug builds, panics if `s` is outside of the range and the policy is [`OutOfRange::Panic`]
+    pub fn interpolate_into(&self, s: S, out: &mut [T; N]) {
+        self.interpolate_in_into(self.get_bucket(s.clone()), s, out);
+    }
+
+    /// Interpolate between the value sets based on the given value, as by [`Self::interpolate`], reporting inputs outside of the range.  
+    /// Useful for catching unnormalized inputs without relying on [`OutOfRange::Panic`], which is only checked in debug builds.
+    ///
+    /// # Errors
+    /// Returns [`OutOfDomain`] if `s` is outside of the range, with the end of the range it was clamped to.  
+    /// Interpolating at `clamped_to` gives the values [`OutOfRange::Clamp`] would have produced.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{LinearInterpolator, OutOfDomain};
+    ///
+    /// let interpolator = LinearInterpolator::new(10.0..=0.0, &[[0.0], [100.0]]);
+    /// assert_eq!(interpolator.try_interpolate(2.5), Ok([75.0]));
+    ///
+    /// let error = interpolator.try_interpolate(-5.0).unwrap_err();
+    /// assert_eq!(error, OutOfDomain { value: -5.0, clamped_to: 0.0 });
+    /// assert_eq!(interpolator.interpolate(error.clamped_to), [100.0]);
+    /// ```
+    pub fn try_interpolate(&self, s: S) -> Result<[T; N], OutOfDomain<S>> {
+        let domain = self.domain();
+        if domain.contains(s.clone()) {
+            return Ok(self.interpolate(s));
+        }
+
+        let (lo, hi) = if domain.is_reversed() {
+            (domain.end, domain.start)
+        } else {
+            (domain.start, domain.end)
+        };
+        let clamped_to = if s < lo { lo } else { hi };
+        Err(OutOfDomain {
+            value: s,
+            clamped_to,
+        })
+    }
+
+    /// Interpolate at each of the given inputs, in order, as by [`Self::interpolate`].
+    ///
+    /// If the inputs are sorted in the direction of the range (ascending, or descending for a reversed range),
+    /// the buckets are walked once from start to end instead of being searched for each input.  
+    /// Otherwise, each input is located with a binary search.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0], [100.0], [0.0]]);
+    /// assert_eq!(interpolator.interpolate_many(&[1.0, 5.0, 9.5]), vec![[20.0], [100.0], [10.0]]);
+    /// assert_eq!(interpolator.interpolate_many(&[9.5, 1.0]), vec![[10.0], [20.0]]);
+    /// ```
+    pub fn interpolate_many(&self, inputs: &[S]) -> Vec<[T; N]> {
+        let rev = self.is_reversed();
+        let in_order = |a: &S, b: &S| if rev { a >= b } else { a <= b };
+        let buckets = self.buckets();
+        if buckets.is_empty() || !inputs.windows(2).all(|w| in_order(&w[0], &w[1])) {
+            return inputs.iter().map(|s| self.interpolate(s.clone())).collect();
+        }
+
+        let mut index = 0;
+        inputs
+            .iter()
+            .map(|s| {
+                while index + 1 < buckets.len() && in_order(&buckets[index + 1].start(), s) {
+                    index += 1;
+                }
+                self.interpolate_in(&buckets[index], s.clone())
+            })
+            .collect()
+    }
+
+    /// Extend the line through a bucket's stops to `s`, saturating at the bounds of `T`
+    fn extrapolate(bucket: &InterpolationBucket<N, S, T>, s: S) -> [T; N] {
+        let start = bucket.start().into_f64();
+        let len = bucket.end().into_f64() - start;
+        let rel_percent = (s.clone().into_f64() - start) / len;
+        if !rel_percent.is_finite() {
+            return bucket.interpolate(s);
+        }
+
+        Self::extrapolate_progress(bucket, rel_percent)
+    }
+
+    /// Extend the line through a bucket's stops to a progress outside of `0.0..=1.0`, saturating at the bounds of `T`
+    fn extrapolate_progress(bucket: &InterpolationBucket<N, S, T>, rel_percent: f64) -> [T; N] {
+        core::array::from_fn(|i| {
+            let lo = bucket.values_lo()[i].clone().into_f64();
+            let hi = bucket.values_hi()[i].clone().into_f64();
+            let value = lo + (hi - lo) * rel_percent;
+            T::from_f64(value).unwrap_or_else(|| saturate(value > 0.0))
+        })
+    }
+
+    /// Interpolate within the given bucket, applying this interpolator's out of range policy, channel modes and output clamp
+    pub(crate) fn interpolate_in(&self, bucket: &InterpolationBucket<N, S, T>, s: S) -> [T; N] {
+        let mut values = [T::ZERO; N];
+        self.interpolate_in_into(bucket, s, &mut values);
+        values
+    }
+
+    /// Interpolate within the given bucket, as by [`Self::interpolate_in`], writing the result into `out`
+    fn interpolate_in_into(&self, bucket: &InterpolationBucket<N, S, T>, s: S, out: &mut [T; N]) {
+        match self.out_of_range {
+            OutOfRange::Clamp => {}
+            _ if self.domain().contains(s.clone()) => {}
+
+            OutOfRange::Panic => {
+                debug_assert!(false, "Input {s} is outside of the interpolator's range");
+            }
+
+            OutOfRange::Saturate => {
+                *out = Self::extrapolate(bucket, s);
+                self.clamp_output_in_place(out);
+                return;
+            }
+        }
+
+        match &self.channel_modes {
+            Some(modes) => *out = bucket.interpolate_with_modes(s, modes),
+            None => bucket.interpolate_into(s, out),
+        }
+        self.clamp_output_in_place(out);
+    }
+
+    /// Interpolate between the value sets based on the given value, wrapping the channels where `wrap[i]` is true.  
+    /// See [`InterpolationBucket::interpolate_wrapping_channels`] for details.
+    ///
+    /// Channel modes and the out of range policy apply only to unwrapped channels, and the output clamp applies to all of them.  
+    /// Wrapped channels are always held at their values at the ends of the range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// // A hue channel that cycles 200 -> 50 the short way, through 255
+    /// let hues = LinearInterpolator::new(0.0..=1.0, &[[200u8], [50]]);
+    /// assert_eq!(hues.interpolate_wrapping_channels(0.5, &[true]), [253]);
+    /// assert_eq!(hues.interpolate(0.5), [125]);
+    /// ```
+    pub fn interpolate_wrapping_channels(&self, s: S, wrap: &[bool; N]) -> [T; N] {
+        let bucket = self.get_bucket(s.clone());
+        let rel_percent = bucket.progress(s.clone());
+        let mut values = self.interpolate_in(bucket, s);
+
+        for (i, value) in values.iter_mut().enumerate() {
+            if wrap[i] {
+                *value = bucket.lerp_channel_wrapping(i, rel_percent);
+            }
+        }
+
+        self.clamp_output(values)
+    }
+
+    /// Interpolate between the value sets based on the given value, reshaping the progress through each bucket with `easing`.  
+    /// See [`InterpolationBucket::interpolate_with_easing`] for details.
+    ///
+    /// Channels set to [`ChannelMode::Step`] are not eased, and the output clamp applies to all channels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{easing::EaseInCubic, LinearInterpolator};
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=20.0, &[[0.0], [100.0], [0.0]]);
+    /// assert_eq!(interpolator.interpolate_with_easing(5.0, &EaseInCubic), [12.5]);
+    /// assert_eq!(interpolator.interpolate_with_easing(15.0, &EaseInCubic), [87.5]);
+    /// ```
+    pub fn interpolate_with_easing(&self, s: S, easing: &impl Easing) -> [T; N] {
+        let bucket = self.get_bucket(s.clone());
+        let values = bucket.interpolate_with_easing(s.clone(), easing);
+        self.finish_eased(bucket, s, values)
+    }
+
+    /// Interpolate between the value sets based on the given value, reshaping the progress of each channel with its own easing.  
+    /// See [`InterpolationBucket::interpolate_per_channel_easing`] for details.
+    ///
+    /// Channels set to [`ChannelMode::Step`] are not eased, and the output clamp applies to all channels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{easing::EasingMode, LinearInterpolator};
+    ///
+    /// // Position eases in, while opacity fades linearly
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0.0, 1.0], [100.0, 0.0]]);
+    /// let easings = [EasingMode::EaseInCubic, EasingMode::Linear];
+    /// assert_eq!(interpolator.interpolate_per_channel_easing(0.5, &easings), [12.5, 0.5]);
+    /// ```
+    pub fn interpolate_per_channel_easing<E: Easing>(&self, s: S, easings: &[E; N]) -> [T; N] {
+        let bucket = self.get_bucket(s.clone());
+        let values = bucket.interpolate_per_channel_easing(s.clone(), easings);
+        self.finish_eased(bucket, s, values)
+    }
+
+    /// Apply this interpolator's stepped channel modes and output clamp to a set of eased values
+    fn finish_eased(
+        &self,
+        bucket: &InterpolationBucket<N, S, T>,
+        s: S,
+        mut values: [T; N],
+    ) -> [T; N] {
+        if let Some(modes) = &self.channel_modes {
+            let stepped = bucket.interpolate_with_modes(s, modes);
+            for (i, (value, stepped)) in values.iter_mut().zip(stepped).enumerate() {
+                if modes[i] == ChannelMode::Step {
+                    *value = stepped;
+                }
+            }
+        }
+
+        self.clamp_output(values)
+    }
+
+    /// Interpolate between the value sets based on the given value, guarding against non-comparable inputs.  
+    /// This is a slower variant of [`Self::interpolate`] intended for untrusted float inputs.
+    ///
+    /// The input policy is as follows:
+    /// - If `s` is not comparable to itself (`NaN`), the first bucket's `values_lo` are returned
+    /// - Otherwise `s` is clamped to the range before searching for a bucket, so `±inf` map to the range's ends
+    ///
+    /// The output clamp, if set, is applied in both cases.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0], [5.0], [10.0]]);
+    /// assert_eq!(interpolator.interpolate_safe(f64::NAN), [0.0]);
+    /// assert_eq!(interpolator.interpolate_safe(f64::INFINITY), [10.0]);
+    /// assert_eq!(interpolator.interpolate_safe(f64::NEG_INFINITY), [0.0]);
+    /// ```
+    pub fn interpolate_safe(&self, s: S) -> [T; N] {
+        let Some(first) = self.buckets().first() else {
+            return [T::ZERO; N];
+        };
+
+        if s.partial_cmp(&s).is_none() {
+            return self.clamp_output(first.values_lo().clone());
+        }
+
+        let domain = self.domain();
+        self.interpolate(s.clamp(domain.start, domain.end))
+    }
+
+    /// Interpolate between the value sets based on the given value, then snap each channel to the nearest multiple of `step[i]`.  
+    /// This intentionally produces banded gradients, such as for retro or dithered effects.
+    ///
+    /// Channels with a step of zero are left unquantized. Multiples that cannot be represented by `T` snap
+    /// to the nearest multiple that can. Quantization happens after the output clamp is applied.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0u8, 0], [255, 255]]);
+    /// assert_eq!(interpolator.interpolate(0.3), [76, 76]);
+    /// assert_eq!(interpolator.quantize_output(0.3, [64, 0]), [64, 76]);
+    /// assert_eq!(interpolator.quantize_output(1.0, [64, 0]), [192, 255]);
+    /// ```
+    pub fn quantize_output(&self, s: S, step: [T; N]) -> [T; N] {
+        let mut values = self.interpolate(s);
+        for (value, step) in values.iter_mut().zip(step) {
+            let step = step.abs().into_f64();
+            if step == 0.0 {
+                continue;
+            }
+
+            let v = value.clone().into_f64();
+            let snapped = (v / step).round() * step;
+            let fallback = if snapped > v {
+                snapped - step
+            } else {
+                snapped + step
+            };
+
+            if let Some(snapped) = T::from_f64(snapped).or_else(|| T::from_f64(fallback)) {
+                *value = snapped;
+            }
+        }
+
+        values
+    }
+
+    /// Create a new interpolator with `new_stop_count` evenly spaced stops, sampled from this one.  
+    /// The range and settings of this interpolator are kept.
+    ///
+    /// This is lossy: any stop of the original that does not land on a new stop is replaced by a straight line
+    /// between its neighbouring samples. The error at any input is therefore at most the largest deviation of
+    /// the original curve from those straight lines, which occurs at the dropped stops.
+    ///
+    /// Returns None if `new_stop_count < 2`, or if it is too large to be represented by type S.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=4.0, &[[0.0], [1.0], [2.0], [3.0], [4.0]]);
+    /// let resampled = interpolator.resample(3).unwrap();
+    ///
+    /// assert_eq!(resampled.buckets().len(), 2);
+    /// assert_eq!(resampled.interpolate(1.0), [1.0]);
+    /// ```
+    #[must_use]
+    pub fn resample(&self, new_stop_count: usize) -> Option<Self> {
+        if new_stop_count < 2 {
+            return None;
+        }
+
+        let value_sets: Vec<_> = self
+            .sample_positions(new_stop_count)
+            .map(|s| self.interpolate(s))
+            .collect();
+        let interpolator = Self::try_new(self.domain(), &value_sets)?;
+        Some(interpolator.with_settings_from(self))
+    }
+
+    /// Create a copy of this interpolator with each stop's value set replaced by the moving average of the `window` stops around it.  
+    /// The averages are taken in f64, and the stops past either end are treated as repeats of the first or last stop.
+    ///
+    /// This softens a noisy gradient, while keeping the same domain, bucket ranges and settings.  
+    /// An even `window` is widened by one, so that it stays centred on each stop; a `window` of 0 or 1 changes nothing.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let noisy = LinearInterpolator::new(0.0..=4.0, &[[0.0], [0.0], [9.0], [0.0], [0.0]]);
+    /// let smoothed = noisy.smooth_stops(3);
+    /// assert_eq!(smoothed.value_sets(), vec![[0.0], [3.0], [3.0], [3.0], [0.0]]);
+    /// ```
+    #[must_use]
+    pub fn smooth_stops(&self, window: usize) -> Self {
+        let stops: Vec<_> = self.stops_iter().map(|(_, values)| values).collect();
+        let radius = window / 2;
+        let last = stops.len().saturating_sub(1);
+
+        let smoothed: Vec<[T; N]> = (0..stops.len())
+            .map(|i| {
+                core::array::from_fn(|channel| {
+                    let (sum, count) = (0..=2 * radius)
+                        .map(|offset| (i + offset).saturating_sub(radius).min(last))
+                        .fold((0.0, 0.0), |(sum, count), j| {
+                            (sum + stops[j][channel].clone().into_f64(), count + 1.0)
+                        });
+                    T::from_f64(sum / count).unwrap_or_else(|| stops[i][channel].clone())
+                })
+            })
+            .collect();
+
+        let buckets = self
+            .buckets()
+            .iter()
+            .zip(smoothed.windows(2))
+            .map(|(b, v)| InterpolationBucket::new(b.range().clone(), v[0].clone(), v[1].clone()))
+            .collect();
+        Self::from_buckets(buckets).with_settings_from(self)
+    }
+
+    /// Join 2 interpolators end-to-end, appending the buckets of `other` after those of `self`.  
+    /// The result keeps the settings (output clamp, etc.) of `self`.
+    ///
+    /// Returns None if `other` does not start where `self` ends, or if their orientations differ.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let first = LinearInterpolator::new(0.0..=50.0, &[[0.0], [10.0]]);
+    /// let second = LinearInterpolator::new(50.0..=100.0, &[[10.0], [0.0]]);
+    ///
+    /// let joined = first.concat(&second).unwrap();
+    /// assert_eq!(joined.interpolate(25.0), [5.0]);
+    /// assert_eq!(joined.interpolate(75.0), [5.0]);
+    ///
+    /// assert!(second.concat(&first).is_none());
+    /// ```
+    #[must_use]
+    pub fn concat(&self, other: &Self) -> Option<Self> {
+        if self.domain().end != other.domain().start || self.is_reversed() != other.is_reversed() {
+            return None;
+        }
+
+        let buckets = self
+            .buckets()
+            .iter()
+            .chain(other.buckets())
+            .cloned()
+            .collect();
+        Some(Self::from_buckets(buckets).with_settings_from(self))
+    }
+
+    /// Sample the interpolator at `count` evenly spaced positions across the range, including both ends,
+    /// returning each position alongside its interpolated values.  
+    /// Positions run from the start of the range to its end, so reversed ranges are walked in descending order.
+    ///
+    /// Useful for exporting a gradient, such as to CSV.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(10.0..=0.0, &[[0.0], [100.0]]);
+    /// assert_eq!(interpolator.tabulate(3), [(10.0, [0.0]), (5.0, [50.0]), (0.0, [100.0])]);
+    /// ```
+    #[must_use]
+    pub fn tabulate(&self, count: usize) -> Vec<(S, [T; N])> {
+        self.sample_positions(count)
+            .map(|s| (s.clone(), self.interpolate(s)))
+            .collect()
+    }
+
+    /// Fold over the values at `count` evenly spaced positions across the range, including both ends.  
+    /// Samples the same positions as [`Self::tabulate`], in the same order, without collecting them.
+    ///
+    /// Useful for computing aggregates, such as the brightest point or average of a gradient.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0], [100.0], [50.0]]);
+    /// let max = interpolator.fold_samples(5, f64::MIN, |max, [v]| max.max(v));
+    /// assert_eq!(max, 100.0);
+    ///
+    /// let mean = interpolator.fold_samples(5, 0.0, |sum, [v]| sum + v) / 5.0;
+    /// assert_eq!(mean, 55.0);
+    /// ```
+    pub fn fold_samples<B, F: FnMut(B, [T; N]) -> B>(&self, count: usize, init: B, mut f: F) -> B {
+        self.sample_positions(count)
+            .fold(init, |acc, s| f(acc, self.interpolate(s)))
+    }
+
+    /// Rasterize the interpolator into `count` values, each the average of the gradient across the span it covers.  
+    /// The range is split into `count` equal spans, from its start to its end, and each is box filtered
+    /// by averaging `oversample` evenly spaced point samples taken at the centers of its sub-spans.
+    ///
+    /// Unlike point sampling with [`Self::tabulate`], this does not skip over detail narrower than a span,
+    /// which reduces aliasing when drawing a gradient at small widths. An `oversample` of `0` is treated as `1`.
+    ///
+    /// Averages are taken in f64, and converted back to type T with truncation, as by interpolation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// // A thin spike that a single sample per pixel would miss
+    /// let interpolator = LinearInterpolator::from_soa(&[0.0, 0.375, 0.5, 0.625, 1.0], &[[0.0], [0.0], [64.0], [0.0], [0.0]]).unwrap();
+    /// assert_eq!(interpolator.sample_averaged(2, 1), vec![[0.0], [0.0]]);
+    /// assert_eq!(interpolator.sample_averaged(2, 8), vec![[8.0], [8.0]]);
+    /// ```
+    #[must_use]
+    pub fn sample_averaged(&self, count: usize, oversample: usize) -> Vec<[T; N]> {
+        let domain = self.domain();
+        let oversample = oversample.max(1);
+        let samples = count.saturating_mul(oversample).into_f64();
+
+        (0..count)
+            .map(|i| {
+                let mut sums = [0.0; N];
+                for j in 0..oversample {
+                    let t = ((i * oversample + j).into_f64() + 0.5) / samples;
+                    let values = self.interpolate(domain.lerp(t));
+                    for (sum, value) in sums.iter_mut().zip(values) {
+                        *sum += value.into_f64();
+                    }
+                }
+
+                sums.map(|sum| {
+                    let average = sum / oversample.into_f64();
+                    T::from_f64(average).unwrap_or_else(|| saturate(average > 0.0))
+                })
+            })
+            .collect()
+    }
+
+    /// Check if this interpolator's buckets and settings are all within `epsilon` of the other's.  
+    /// Values are compared as f64; see [`Self::relative_eq`] for a tolerance that scales with magnitude.
+    ///
+    /// Unlike `==`, this is useful for comparing float interpolators built in different ways.  
+    /// Both interpolators must have the same number of buckets, and the same channel modes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let a = LinearInterpolator::new(0.0..=0.3, &[[0.0], [1.0]]);
+    /// let b = LinearInterpolator::new(0.0..=0.1 + 0.2, &[[0.0], [1.0]]);
+    /// assert_ne!(a, b);
+    /// assert!(a.abs_diff_eq(&b, 1e-9));
+    /// ```
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.relative_eq(other, epsilon, 0.0)
+    }
+
+    /// Check if this interpolator's buckets and settings are all approximately equal to the other's.  
+    /// Values are equal if they differ by at most `epsilon`, or by at most `max_relative` times the larger of their magnitudes.
+    pub fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        let buckets_eq = self.buckets().len() == other.buckets().len()
+            && self
+                .buckets()
+                .iter()
+                .zip(other.buckets())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative));
+
+        let clamp_eq = match (&self.output_clamp, &other.output_clamp) {
+            (Some((min_a, max_a)), Some((min_b, max_b))) => {
+                let bucket_a =
+                    InterpolationBucket::new((S::ZERO, S::ZERO), min_a.clone(), max_a.clone());
+                let bucket_b =
+                    InterpolationBucket::new((S::ZERO, S::ZERO), min_b.clone(), max_b.clone());
+                bucket_a.relative_eq(&bucket_b, epsilon, max_relative)
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        buckets_eq
+            && clamp_eq
+            && self.channel_modes == other.channel_modes
+            && self.out_of_range == other.out_of_range
+    }
+
+    /// Check if this interpolator describes the same mapping as the other, regardless of the orientation it is stored in.  
+    /// Stops are compared in ascending order of position, so a forward interpolator equals its reversed equivalent.
+    ///
+    /// Settings such as the output clamp and channel modes must still match exactly.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let forward = LinearInterpolator::new(0.0..=10.0, &[[0.0], [50.0], [100.0]]);
+    /// let reversed = LinearInterpolator::new(10.0..=0.0, &[[100.0], [50.0], [0.0]]);
+    /// assert_ne!(forward, reversed);
+    /// assert!(forward.semantically_eq(&reversed));
+    /// ```
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        fn ascending_stops<'i, const N: usize, S: Numeric, T: Numeric>(
+            interpolator: &'i LinearInterpolator<'_, N, S, T>,
+        ) -> Vec<(S, &'i [T; N])> {
+            let mut stops: Vec<_> = interpolator.stops_iter().collect();
+            if interpolator.is_reversed() {
+                stops.reverse();
+            }
+            stops
+        }
+
+        ascending_stops(self) == ascending_stops(other)
+            && self.output_clamp == other.output_clamp
+            && self.channel_modes == other.channel_modes
+            && self.out_of_range == other.out_of_range
+    }
+
+    /// Precompute `interpolate(s)` for every whole value of `S` across the range, in ascending order of `s`.  
+    /// Index `i` of the table holds the result for the lowest value in the range plus `i`.
+    ///
+    /// This is intended for small integer domains like `u8` or `u16`; see [`crate::TableInterpolator`].  
+    /// **Warning:** the table holds one `[T; N]` per value in the range, so the memory cost is
+    /// `range.len() * N * size_of::<T>()` - a full `u32` domain of `[f32; 4]` would be 64GiB.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(4u8..=0, &[[0.0], [100.0]]);
+    /// assert_eq!(interpolator.bake_table(), vec![[100.0], [75.0], [50.0], [25.0], [0.0]]);
+    /// ```
+    #[must_use]
+    pub fn bake_table(&self) -> Vec<[T; N]> {
+        let domain = self.domain();
+        let (lo, hi) = if domain.is_reversed() {
+            (domain.end, domain.start)
+        } else {
+            (domain.start, domain.end)
+        };
+
+        let mut table = Vec::new();
+        let mut s = lo;
+        loop {
+            table.push(self.interpolate(s.clone()));
+            match s.clone().checked_add(S::ONE) {
+                // Guard against float ranges too large for ONE to make progress
+                Some(next) if next > s && next <= hi => s = next,
+                _ => break,
+            }
+        }
+        table
+    }
+
+    /// Convert this interpolator's values to another numeric type, keeping the same range.  
+    /// Each value is converted through `f64`.
+    ///
+    /// Returns None if any value (including the output clamp bounds, if set) cannot be represented by type U.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{interpolators::F32LinearInterpolator, LinearInterpolator};
+    ///
+    /// let interpolator = F32LinearInterpolator::new(0.0..=1.0, &[[255.0, 0.0, 0.0], [0.0, 0.0, 255.0]]);
+    /// let bytes: LinearInterpolator<3, f32, u8> = interpolator.cast_values().unwrap();
+    /// assert_eq!(bytes.interpolate(1.0), [0, 0, 255]);
+    /// ```
+    #[must_use]
+    pub fn cast_values<U: Numeric>(&self) -> Option<LinearInterpolator<'static, N, S, U>> {
+        fn cast<const N: usize, T: Numeric, U: Numeric>(values: &[T; N]) -> Option<[U; N]> {
+            let mut out = [U::ZERO; N];
+            for (out, value) in out.iter_mut().zip(values) {
+                *out = U::from_f64(value.clone().into_f64())?;
+            }
+            Some(out)
+        }
+
+        let buckets = self
+            .buckets()
+            .iter()
+            .map(|b| {
+                let lo = cast(b.values_lo())?;
+                let hi = cast(b.values_hi())?;
+                Some(InterpolationBucket::new(b.range().clone(), lo, hi))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut interpolator = LinearInterpolator::from_buckets(buckets);
+        if let Some((min, max)) = &self.output_clamp {
+            interpolator.output_clamp = Some((cast(min)?, cast(max)?));
+        }
+        interpolator.channel_modes = self.channel_modes;
+        interpolator.out_of_range = self.out_of_range;
+
+        Some(interpolator)
+    }
+
+    /// Get the lowest and highest value of each channel across all stops, as `(min, max)` pairs compared in f64.  
+    /// These are the bounds used by [`Self::normalize_values`]; a normalized value `t` maps back to `min + t * (max - min)`.
+    ///
+    /// Returns `(0.0, 0.0)` for every channel if there are no stops.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &[[10u8, 0], [50, 200], [30, 100]]);
+    /// assert_eq!(interpolator.channel_bounds(), [(10.0, 50.0), (0.0, 200.0)]);
+    /// ```
+    #[must_use]
+    pub fn channel_bounds(&self) -> [(f64, f64); N] {
+        let mut bounds = [(f64::INFINITY, f64::NEG_INFINITY); N];
+        for (_, values) in self.stops_iter() {
+            for ((min, max), value) in bounds.iter_mut().zip(values) {
+                let value = value.clone().into_f64();
+                *min = min.min(value);
+                *max = max.max(value);
+            }
+        }
+
+        if self.buckets().is_empty() {
+            bounds = [(0.0, 0.0); N];
+        }
+        bounds
+    }
+
+    /// Create a copy of this interpolator with each channel rescaled so that its lowest value across all stops is `0.0`,
+    /// and its highest is `1.0`. Useful for comparing the shape of gradients regardless of their magnitude.
+    ///
+    /// Channels that hold a single value everywhere become `0.0`.  
+    /// The range and settings of this interpolator are kept, with the output clamp, if set, rescaled to match.
+    ///
+    /// Use [`Self::channel_bounds`] to get the `(min, max)` of each channel, to map normalized values back.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=2.0, &[[10u8, 0], [50, 200], [30, 100]]);
+    /// let normalized = interpolator.normalize_values();
+    /// assert_eq!(normalized.interpolate(0.0), [0.0, 0.0]);
+    /// assert_eq!(normalized.interpolate(1.0), [1.0, 1.0]);
+    /// assert_eq!(normalized.interpolate(2.0), [0.5, 0.5]);
+    /// ```
+    #[must_use]
+    pub fn normalize_values(&self) -> LinearInterpolator<'static, N, S, f64> {
+        let bounds = self.channel_bounds();
+        let normalize = |values: &[T; N]| {
+            let mut out = [0.0; N];
+            for ((out, value), (min, max)) in out.iter_mut().zip(values).zip(bounds) {
+                if max > min {
+                    *out = (value.clone().into_f64() - min) / (max - min);
+                }
+            }
+            out
+        };
+
+        let buckets = self
+            .buckets()
+            .iter()
+            .map(|b| {
+                let lo = normalize(b.values_lo());
+                let hi = normalize(b.values_hi());
+                InterpolationBucket::new(b.range().clone(), lo, hi)
+            })
+            .collect();
+
+        let mut interpolator = LinearInterpolator::from_buckets(buckets);
+        if let Some((min, max)) = &self.output_clamp {
+            interpolator.output_clamp = Some((normalize(min), normalize(max)));
+        }
+        interpolator.channel_modes = self.channel_modes;
+        interpolator.out_of_range = self.out_of_range;
+        interpolator
+    }
+
+    /// Create a new interpolator with every stop's values multiplied by `factor`.  
+    /// The range and settings of this interpolator are kept; the output clamp, if set, is not scaled.
+    ///
+    /// Values that cannot be represented by type T after scaling saturate to the nearest representable value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &[[255u8, 0, 0], [0, 0, 255]]);
+    /// let dimmed = interpolator.scale_output(0.5);
+    /// assert_eq!(dimmed.interpolate(0.0), [127, 0, 0]);
+    ///
+    /// let brightened = interpolator.scale_output(2.0);
+    /// assert_eq!(brightened.interpolate(1.0), [0, 0, 255]);
+    /// ```
+    #[must_use]
+    pub fn scale_output(&self, factor: f64) -> Self {
+        self.map_values(|values| values.clone().map(|value| saturating_scale(value, factor)))
+    }
+
+    /// Create a new interpolator with `delta` added, per channel, to every stop's values.  
+    /// The range and settings of this interpolator are kept; the output clamp, if set, is not shifted.
+    ///
+    /// Values that overflow type T saturate to the nearest representable value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// // Raise the black level of a greyscale ramp
+    /// let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0u8, 0, 0], [255, 255, 255]]);
+    /// let lifted = interpolator.offset_output([16, 16, 16]);
+    /// assert_eq!(lifted.interpolate(0.0), [16, 16, 16]);
+    /// assert_eq!(lifted.interpolate(1.0), [255, 255, 255]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)] // Inputs are taken by value throughout the API, as they are usually Copy
+    pub fn offset_output(&self, delta: [T; N]) -> Self {
+        self.map_values(|values| {
+            let mut out = values.clone();
+            for (value, delta) in out.iter_mut().zip(&delta) {
+                *value = saturating_offset(value.clone(), delta.clone());
+            }
+            out
+        })
+    }
+
+    /// Get the area under the given channel across the whole range, in f64.  
+    /// Each bucket contributes the trapezoidal area between its 2 stops, which is exact for a piecewise-linear channel.
+    ///
+    /// Widths are always positive, so reversed ranges produce the same area as their forward equivalent.  
+    /// The output clamp is not applied, and stepped channels produce the same area as continuous ones.
+    ///
+    /// # Panics
+    /// Panics if `channel` is not less than `N`
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let power = LinearInterpolator::new(0.0..=10.0, &[[0.0], [100.0], [100.0]]);
+    /// assert_eq!(power.integrate_channel(0), 750.0);
+    /// ```
+    #[must_use]
+    pub fn integrate_channel(&self, channel: usize) -> f64 {
+        self.buckets()
+            .iter()
+            .map(|b| {
+                let width = b.range().len().into_f64();
+                let lo = b.values_lo()[channel].clone().into_f64();
+                let hi = b.values_hi()[channel].clone().into_f64();
+                width * (lo + hi) / 2.0
+            })
+            .sum()
+    }
+
+    /// Get the area under each channel across the whole range, in f64.  
+    /// See [`Self::integrate_channel`] for details.
+    #[must_use]
+    pub fn integrate(&self) -> [f64; N] {
+        core::array::from_fn(|i| self.integrate_channel(i))
+    }
+
+    /// Get the total length of the path traced through value space, in f64.  
+    /// This is the sum of the Euclidean distances between each bucket's `values_lo` and `values_hi`, across all channels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let path = LinearInterpolator::new(0.0..=2.0, &[[0.0, 0.0], [3.0, 4.0], [3.0, 0.0]]);
+    /// assert_eq!(path.arc_length(), 9.0);
+    /// ```
+    #[must_use]
+    pub fn arc_length(&self) -> f64 {
+        self.buckets().iter().map(Self::bucket_arc_length).sum()
+    }
+
+    /// Get the length of the path traced through value space from the start of the range up to `s`, in f64.  
+    /// See [`Self::arc_length`] for details; inputs outside of the range are clamped to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let path = LinearInterpolator::new(0.0..=2.0, &[[0.0, 0.0], [3.0, 4.0], [3.0, 0.0]]);
+    /// assert_eq!(path.arc_length_at(0.5), 2.5);
+    /// assert_eq!(path.arc_length_at(1.5), 7.0);
+    /// assert_eq!(path.arc_length_at(10.0), 9.0);
+    /// ```
+    #[must_use]
+    pub fn arc_length_at(&self, s: S) -> f64 {
+        let buckets = self.buckets();
+        if buckets.is_empty() {
+            return 0.0;
+        }
+
+        let index = self.bucket_index(s.clone());
+        let before: f64 = buckets[..index].iter().map(Self::bucket_arc_length).sum();
+        before + Self::bucket_arc_length(&buckets[index]) * buckets[index].progress(s)
+    }
+
+    /// Interpolate at `fraction` of the way along the path traced through value space, from `0.0` at its start to `1.0` at its end.  
+    /// Unlike [`Self::interpolate`], equal steps of `fraction` cover equal distances between values, regardless of
+    /// how far apart the stops are, giving constant-speed motion along a multi-segment gradient. See [`Self::arc_length`].
+    ///
+    /// The point is mapped back to an input of type `S` before interpolating, so settings like the output clamp still apply;  
+    /// for integer ranges, this rounds the result to the nearest whole input before it. `fraction` is clamped to `0.0..=1.0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// // The second segment covers 3 times the distance of the first
+    /// let path = LinearInterpolator::new(0.0..=2.0, &[[0.0], [10.0], [40.0]]);
+    /// assert_eq!(path.interpolate(1.0), [10.0]);
+    /// assert_eq!(path.interpolate_by_arc_length(0.25), [10.0]);
+    /// assert_eq!(path.interpolate_by_arc_length(0.625), [25.0]);
+    /// ```
+    #[must_use]
+    pub fn interpolate_by_arc_length(&self, fraction: f64) -> [T; N] {
+        let buckets = self.buckets();
+        let Some(last) = buckets.last() else {
+            return [T::ZERO; N];
+        };
+
+        let target = self.arc_length() * fraction.clamp(0.0, 1.0);
+        let mut travelled = 0.0;
+        for bucket in buckets {
+            let length = Self::bucket_arc_length(bucket);
+            if length > 0.0 && travelled + length >= target {
+                let s = bucket.range().lerp((target - travelled) / length);
+                return self.interpolate_in(bucket, s);
+            }
+
+            travelled += length;
+        }
+
+        // The path has no length, or rounding left the target just past its end
+        self.interpolate_in(last, last.end())
+    }
+
+    /// Interpolate at a fractional stop index, where `0.0` is the first stop, `1.0` the second, and so on.  
+    /// Values between whole numbers blend between the stops either side, so `1.5` is halfway from the second stop to the third,
+    /// regardless of where those stops sit in the range.
+    ///
+    /// The index is mapped back to an input of type `S` before interpolating, so settings like the output clamp still apply;  
+    /// for integer ranges, this rounds the result to the nearest whole input before it.
+    /// `frac` is clamped to `0.0..=(stop_count - 1)`, and zeros are returned if there are no stops.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// // Uneven stops, addressed by index
+    /// let interpolator = LinearInterpolator::from_soa(&[0.0, 10.0, 100.0], &[[0.0], [1.0], [2.0]]).unwrap();
+    /// assert_eq!(interpolator.interpolate_at_stop_fraction(0.5), [0.5]);
+    /// assert_eq!(interpolator.interpolate_at_stop_fraction(1.5), [1.5]);
+    /// assert_eq!(interpolator.interpolate_at_stop_fraction(5.0), [2.0]);
+    /// ```
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn interpolate_at_stop_fraction(&self, frac: f64) -> [T; N] {
+        let buckets = self.buckets();
+        if buckets.is_empty() {
+            return [T::ZERO; N];
+        }
+
+        let frac = frac.max(0.0).min(buckets.len() as f64);
+        let index = (frac as usize).min(buckets.len() - 1);
+        let bucket = &buckets[index];
+        let s = bucket.range().lerp(frac - index as f64);
+        self.interpolate_in(bucket, s)
+    }
+
+    /// Interpolate with the input on a logarithmic axis, so that equal ratios of `s` cover equal distances.  
+    /// The `log10` of `s` is measured between the `log10` of the ends of the range, and the bucket is located at that fraction of the range;
+    /// with 3 stops over `20.0..=20000.0`, the middle stop is reached at 632 rather than 10010. Useful for audio frequencies, where each octave should look the same.
+    ///
+    /// Inputs outside of the range follow the [`OutOfRange`] policy. Zero and negative inputs have no logarithm,
+    /// so they are first moved to the lowest value in the range; with [`OutOfRange::Clamp`] they map to it.
+    ///
+    /// Returns None if the range includes zero or negative values, where the logarithm is undefined.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(1.0..=100.0, &[[0.0], [1.0], [2.0]]);
+    /// assert_eq!(interpolator.interpolate_log(10.0), Some([1.0]));
+    /// assert_eq!(interpolator.interpolate_log(100.0), Some([2.0]));
+    /// assert_eq!(interpolator.interpolate_log(-5.0), Some([0.0]));
+    ///
+    /// let invalid = LinearInterpolator::new(0.0..=1000.0, &[[0.0], [1.0]]);
+    /// assert_eq!(invalid.interpolate_log(100.0), None);
+    /// ```
+    ///
+    /// # Panics
+    /// In debug builds, panics if `s` is outside of the range and the policy is [`OutOfRange::Panic`]
+    #[must_use]
+    #[allow(clippy::float_cmp)] // The end of the range is matched exactly, so the last stop is reached without rounding
+    pub fn interpolate_log(&self, s: S) -> Option<[T; N]> {
+        let domain = self.domain();
+        let (start, end) = (
+            domain.start.clone().into_f64(),
+            domain.end.clone().into_f64(),
+        );
+        let min = start.min(end);
+        if min.is_nan() || min <= 0.0 {
+            return None;
+        }
+
+        let mut extrapolate = false;
+        if !domain.contains(s.clone()) {
+            match self.out_of_range {
+                OutOfRange::Clamp => {}
+                OutOfRange::Panic => {
+                    debug_assert!(false, "Input {s} is outside of the interpolator's range");
+                }
+                OutOfRange::Saturate => extrapolate = true,
+            }
+        }
+
+        // The fraction of the way along the range, on the logarithmic axis
+        let log_width = end.log10() - start.log10();
+        let mut frac = if log_width == 0.0 {
+            0.0
+        } else {
+            (s.into_f64().max(min).log10() - start.log10()) / log_width
+        };
+        if !extrapolate {
+            frac = frac.clamp(0.0, 1.0);
+        }
+
+        // Locate the bucket at the same fraction of the linear range, finishing exactly on the last stop
+        let position = if frac == 1.0 {
+            end
+        } else {
+            start + (end - start) * frac
+        };
+        let buckets = self.buckets();
+        let index = buckets
+            .partition_point(|b| {
+                let bucket_end = b.end().into_f64();
+                if start > end {
+                    bucket_end > position
+                } else {
+                    bucket_end < position
+                }
+            })
+            .min(buckets.len() - 1);
+
+        let bucket = &buckets[index];
+        let bucket_start = bucket.start().into_f64();
+        let width = bucket.end().into_f64() - bucket_start;
+        let progress = if width == 0.0 {
+            0.0
+        } else {
+            (position - bucket_start) / width
+        };
+
+        let mut values = if extrapolate {
+            Self::extrapolate_progress(bucket, progress)
+        } else {
+            let progress = progress.clamp(0.0, 1.0);
+            match &self.channel_modes {
+                Some(modes) => bucket.interpolate_progress_with_modes(progress, modes),
+                None => bucket.interpolate_progress(progress),
+            }
+        };
+        self.clamp_output_in_place(&mut values);
+        Some(values)
+    }
+
+    /// The Euclidean distance between a bucket's value sets, in f64
+    fn bucket_arc_length(bucket: &InterpolationBucket<N, S, T>) -> f64 {
+        bucket
+            .values_lo()
+            .iter()
+            .zip(bucket.values_hi())
+            .map(|(lo, hi)| {
+                let delta = hi.clone().into_f64() - lo.clone().into_f64();
+                delta * delta
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Get the slope of each channel in every bucket, as `(hi - lo) / (end - start)` in f64.  
+    /// Slopes are in the stored orientation, so a channel rising across a reversed range has a negative slope.
+    ///
+    /// Useful for finding kinks in a gradient, where the slopes either side of a stop do not match.  
+    /// A zero-width bucket divides by zero, so its slopes are infinite, or NaN where its values are equal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0], [10.0], [0.0]]);
+    /// assert_eq!(interpolator.slopes(), vec![[2.0], [-2.0]]);
+    ///
+    /// let reversed = LinearInterpolator::new(10.0..=0.0, &[[0.0], [10.0]]);
+    /// assert_eq!(reversed.slopes(), vec![[-1.0]]);
+    /// ```
+    #[must_use]
+    pub fn slopes(&self) -> Vec<[f64; N]> {
+        self.buckets()
+            .iter()
+            .map(|b| {
+                let width = b.end().into_f64() - b.start().into_f64();
+                core::array::from_fn(|i| {
+                    let lo = b.values_lo()[i].clone().into_f64();
+                    let hi = b.values_hi()[i].clone().into_f64();
+                    (hi - lo) / width
+                })
+            })
+            .collect()
+    }
+
+    /// Find the stops where the gradient has a visible corner: where the [`Self::slopes`] of the buckets either side
+    /// differ by more than `tolerance` in any channel. Returns the index of each such stop, in stored order.
+    ///
+    /// The first and last stops are never kinks. A zero-width bucket that jumps between values has an infinite slope,
+    /// so both of its stops are reported; one holding equal values has no slope, and is skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// // A straight line from stop 0 to stop 2, then a turn at stop 2
+    /// let interpolator = LinearInterpolator::new(0.0..=3.0, &[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [2.0, 1.5]]);
+    /// assert_eq!(interpolator.kinks(0.1), vec![2]);
+    /// assert!(interpolator.kinks(2.0).is_empty());
+    /// ```
+    #[must_use]
+    pub fn kinks(&self, tolerance: f64) -> Vec<usize> {
+        let slopes = self.slopes();
+        slopes
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| {
+                pair[0]
+                    .iter()
+                    .zip(&pair[1])
+                    .any(|(a, b)| (a - b).abs() > tolerance)
+            })
+            .map(|(i, _)| i + 1)
+            .collect()
+    }
+
+    /// Returns, per channel, the direction it moves in from the start of the range to its end, found by scanning the stops.
+    ///
+    /// [`Self::reverse_interpolate_masked`] can only give a unique answer for channels that are [`Monotonicity::Increasing`]
+    /// or [`Monotonicity::Decreasing`], and only outside of any flat spans, where those channels hold a value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::{LinearInterpolator, Monotonicity};
+    ///
+    /// let interpolator = LinearInterpolator::new(10.0..=0.0, &[[0, 5, 9, 1], [5, 5, 8, 2], [10, 5, 7, 1]]);
+    /// assert_eq!(interpolator.channel_monotonicity(), [
+    ///     Monotonicity::Increasing,
+    ///     Monotonicity::Constant,
+    ///     Monotonicity::Decreasing,
+    ///     Monotonicity::NonMonotonic,
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn channel_monotonicity(&self) -> [Monotonicity; N] {
+        core::array::from_fn(|i| {
+            let mut values = self
+                .buckets()
+                .iter()
+                .flat_map(|b| [&b.values_lo()[i], &b.values_hi()[i]]);
+            let Some(mut previous) = values.next() else {
+                return Monotonicity::Constant;
+            };
+
+            let (mut increases, mut decreases) = (false, false);
+            for value in values {
+                match value.partial_cmp(previous) {
+                    Some(core::cmp::Ordering::Greater) => increases = true,
+                    Some(core::cmp::Ordering::Less) => decreases = true,
+                    Some(core::cmp::Ordering::Equal) => {}
+                    None => return Monotonicity::NonMonotonic,
+                }
+                previous = value;
+            }
+
+            match (increases, decreases) {
+                (true, false) => Monotonicity::Increasing,
+                (false, true) => Monotonicity::Decreasing,
+                (false, false) => Monotonicity::Constant,
+                (true, true) => Monotonicity::NonMonotonic,
+            }
+        })
+    }
+
+    /// Build the inverse of a single channel, as an interpolator from that channel's values back to the input.  
+    /// Each stop becomes a stop of the inverse, with the channel's value as its position, so the inverse is exact
+    /// wherever the channel is; a reusable form of [`Self::reverse_interpolate_masked`].
+    ///
+    /// The channel's range runs in the direction the channel moves, so a decreasing channel gives a reversed range.  
+    /// Flat spans, where the channel holds a value, become a jump in the inverse, which maps that value to one end of the span.
+    /// Settings such as the output clamp are not carried over.
+    ///
+    /// Returns None if `channel` is out of bounds, or is not [`Monotonicity::Increasing`] or [`Monotonicity::Decreasing`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0, 5.0], [20.0, 5.0], [100.0, 5.0]]);
+    /// let inverse = interpolator.invert_channel(0).unwrap();
+    /// assert_eq!(inverse.interpolate(10.0), [2.5]);
+    /// assert_eq!(inverse.interpolate(60.0), [7.5]);
+    ///
+    /// // A constant channel has no inverse
+    /// assert!(interpolator.invert_channel(1).is_none());
+    /// ```
+    #[must_use]
+    pub fn invert_channel(&self, channel: usize) -> Option<LinearInterpolator<'static, 1, T, S>> {
+        match self.channel_monotonicity().get(channel)? {
+            Monotonicity::Increasing | Monotonicity::Decreasing => {}
+            Monotonicity::Constant | Monotonicity::NonMonotonic => return None,
+        }
+
+        let (positions, value_sets): (Vec<_>, Vec<_>) = self
+            .stops_iter()
+            .map(|(s, values)| (values[channel].clone(), [s]))
+            .unzip();
+        LinearInterpolator::from_soa(&positions, &value_sets)
+    }
+
+    /// Returns, per channel, the input value at which that channel reaches its maximum across the whole range.
+    ///
+    /// Since each channel is piecewise-linear, the maximum always occurs at a stop.  
+    /// Ties resolve to the stop closest to the start of the range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(100.0..=0.0, &[[0.0, 5.0], [10.0, 0.0], [5.0, 0.0]]);
+    /// assert_eq!(interpolator.argmax(), [50.0, 100.0]);
+    /// ```
+    #[must_use]
+    pub fn argmax(&self) -> [S; N] {
+        self.arg_extreme(|candidate, best| candidate > best)
+    }
+
+    /// Returns, per channel, the input value at which that channel reaches its minimum across the whole range.
+    ///
+    /// Since each channel is piecewise-linear, the minimum always occurs at a stop.  
+    /// Ties resolve to the stop closest to the start of the range.
+    #[must_use]
+    pub fn argmin(&self) -> [S; N] {
+        self.arg_extreme(|candidate, best| candidate < best)
+    }
+
+    /// Returns the index of the largest channel in the interpolated value set at `s`.  
+    /// Ties resolve to the lowest index, and 0 is returned if `N` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[255u8, 0, 0], [0, 0, 255]]);
+    /// assert_eq!(interpolator.dominant_channel(2.0), 0);
+    /// assert_eq!(interpolator.dominant_channel(5.0), 0);
+    /// assert_eq!(interpolator.dominant_channel(8.0), 2);
+    /// ```
+    #[must_use]
+    pub fn dominant_channel(&self, s: S) -> usize {
+        let values = self.interpolate(s);
+        let mut dominant = 0;
+        for (i, value) in values.iter().enumerate().skip(1) {
+            if *value > values[dominant] {
+                dominant = i;
+            }
+        }
+
+        dominant
+    }
+
+    /// Scan the stops, keeping per-channel the position of the value preferred by `is_better`
+    fn arg_extreme(&self, is_better: impl Fn(&T, &T) -> bool) -> [S; N] {
+        let mut stops = self.stops_iter();
+        let Some((first_s, first_values)) = stops.next() else {
+            return [S::ZERO; N];
+        };
+
+        let mut positions: [S; N] = core::array::from_fn(|_| first_s.clone());
+        let mut best: [&T; N] = core::array::from_fn(|i| &first_values[i]);
+        for (s, values) in stops {
+            for i in 0..N {
+                if is_better(&values[i], best[i]) {
+                    best[i] = &values[i];
+                    positions[i] = s.clone();
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Get the full range covered by this interpolator's buckets
+    pub(crate) fn domain(&self) -> ReversibleRange<S> {
+        let buckets = self.buckets();
+        match (buckets.first(), buckets.last()) {
+            (Some(first), Some(last)) => ReversibleRange::new(first.start(), last.end()),
+            _ => ReversibleRange::new(S::ZERO, S::ZERO),
+        }
+    }
+
+    /// Iterate over `count` evenly spaced positions across the range, including both ends
+    fn sample_positions(&self, count: usize) -> impl Iterator<Item = S> {
+        let domain = self.domain();
+        let last = count.saturating_sub(1).max(1).into_f64();
+
+        (0..count).map(move |i| {
+            if i + 1 == count && count > 1 {
+                domain.end.clone()
+            } else {
+                domain.lerp(i.into_f64() / last)
+            }
+        })
+    }
+
+    /// Build an interpolator with a bucket between each pair of consecutive stops.  
+    /// `positions` and `value_sets` must have the same, non-zero, length
+    pub(crate) fn from_stops(positions: &[S], value_sets: &[[T; N]]) -> Self {
+        if let ([position], [values]) = (positions, value_sets) {
+            let range = (position.clone(), position.clone());
+            let bucket = InterpolationBucket::new(range, values.clone(), values.clone());
+            return Self::from_buckets(vec![bucket]);
+        }
+
+        let buckets = positions
+            .windows(2)
+            .zip(value_sets.windows(2))
+            .map(|(p, v)| {
+                let range = (p[0].clone(), p[1].clone());
+                InterpolationBucket::new(range, v[0].clone(), v[1].clone())
+            })
+            .collect();
+        Self::from_buckets(buckets)
+    }
+
+    /// Copy this interpolator's settings (output clamp, etc.) onto another interpolator
+    fn with_settings_from(mut self, source: &Self) -> Self {
+        self.output_clamp.clone_from(&source.output_clamp);
+        self.channel_modes = source.channel_modes;
+        self.out_of_range = source.out_of_range;
+        self
+    }
+
+    /// Create a copy of this interpolator with `f` applied to every stop's value set, keeping its settings
+    fn map_values(&self, f: impl Fn(&[T; N]) -> [T; N]) -> Self {
+        let buckets = self
+            .buckets()
+            .iter()
+            .map(|b| {
+                InterpolationBucket::new(b.range().clone(), f(b.values_lo()), f(b.values_hi()))
+            })
+            .collect();
+        Self::from_buckets(buckets).with_settings_from(self)
+    }
+
+    /// Iterate over the stops of this interpolator, in stored order, as `(position, values)` pairs
+    pub(crate) fn stops_iter(&self) -> impl Iterator<Item = (S, &[T; N])> {
+        let first = self.buckets().first().map(|b| (b.start(), b.values_lo()));
+        let rest = self.buckets().iter().map(|b| (b.end(), b.values_hi()));
+        first.into_iter().chain(rest)
+    }
+
+    /// Find the stop whose value set is nearest to `query`, by Euclidean distance with values compared as f64.  
+    /// Returns the stop's value set and its position in the range. Ties resolve to the stop closest to the start of the range.
+    ///
+    /// Unlike [`Self::reverse_interpolate`], this finds a match for any comparable query, and only considers the stops themselves;  
+    /// useful for quantizing values to a palette.  
+    /// Returns zeros if there are no stops, or if no distance is comparable, such as when `query` contains NaN.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let palette = LinearInterpolator::new(0.0..=2.0, &[[255u8, 0, 0], [0, 255, 0], [0, 0, 255]]);
+    /// assert_eq!(palette.nearest_stop(&[10, 200, 60]), ([0, 255, 0], 1.0));
+    /// ```
+    pub fn nearest_stop(&self, query: &[T; N]) -> ([T; N], S) {
+        let distance = |values: &[T; N]| -> f64 {
+            values
+                .iter()
+                .zip(query)
+                .map(|(value, query)| {
+                    let d = value.clone().into_f64() - query.clone().into_f64();
+                    d * d
+                })
+                .sum()
+        };
+
+        let mut nearest = ([T::ZERO; N], S::ZERO);
+        let mut nearest_distance = f64::INFINITY;
+        for (position, values) in self.stops_iter() {
+            let d = distance(values);
+            if d < nearest_distance {
+                nearest = (values.clone(), position);
+                nearest_distance = d;
+            }
+        }
+        nearest
+    }
+
+    /// Attempt to find a value in the valid range that could produce the given set of values.
+    ///
+    /// This may be slow, since all buckets may be checked
+    pub fn reverse_interpolate(&self, values: &[T; N]) -> Option<S> {
+        self.reverse_interpolate_masked(values, &[true; N])
+    }
+
+    /// Attempt to find a value in the valid range that could produce the given set of values.  
+    /// Only channels where `mask[i]` is true are considered, for example to ignore an alpha channel.
+    ///
+    /// This may be slow, since all buckets may be checked
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0, 0, 255], [100, 200, 0]]);
+    /// let mask = [true, true, false];
+    /// assert_eq!(interpolator.reverse_interpolate_masked(&[50, 100, 10], &mask), Some(5.0));
+    /// ```
+    pub fn reverse_interpolate_masked(&self, values: &[T; N], mask: &[bool; N]) -> Option<S> {
+        self.buckets()
+            .iter()
+            .find_map(|bucket| bucket.reverse_interpolate_masked(values, mask))
+    }
+
+    /// Find the value in the valid range that best matches the given set of values, along with a confidence in `0.0..=1.0`.  
+    /// Unlike [`Self::reverse_interpolate`], the channels do not need to agree exactly, so this suits noisy data.
+    ///
+    /// Within each bucket, every channel that changes gives its own estimate of how far through the bucket the values lie.  
+    /// Their mean is the answer, and the confidence is `1.0` minus the furthest any channel's estimate is from it,
+    /// in fractions of the bucket; exact matches score `1.0`, and the bucket with the highest score wins.  
+    /// A channel that is constant across a bucket must match exactly, or that bucket scores `0.0`.
+    ///
+    /// Returns None if no channel changes anywhere in the interpolator. This may be slow, since all buckets are checked.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator = LinearInterpolator::new(0.0..=8.0, &[[0, 0], [64, 64]]);
+    /// assert_eq!(interpolator.reverse_interpolate_scored(&[32, 32]), Some((4.0, 1.0)));
+    ///
+    /// // The channels disagree slightly, so the match is less certain
+    /// assert_eq!(interpolator.reverse_interpolate_scored(&[30, 34]), Some((4.0, 0.968_75)));
+    /// assert_eq!(interpolator.reverse_interpolate(&[30, 34]), None);
+    /// ```
+    pub fn reverse_interpolate_scored(&self, values: &[T; N]) -> Option<(S, f64)> {
+        let mut best: Option<(&InterpolationBucket<N, S, T>, f64, f64)> = None;
+        for bucket in self.buckets() {
+            let Some((progress, confidence)) = bucket.reverse_interpolate_scored(values) else {
+                continue;
+            };
+
+            if best.is_none_or(|(.., best)| confidence > best) {
+                best = Some((bucket, progress, confidence));
+            }
+        }
+
+        let (bucket, progress, confidence) = best?;
+        Some((bucket.range().lerp(progress), confidence))
+    }
+}
+
+/// Multiplies every stop's values by a factor, as [`LinearInterpolator::scale_output`] does.  
+/// Results that cannot be represented by type T saturate to its maximum or lowest value, and integer results truncate towards zero.
+///
+/// ```rust
+/// use lineic::LinearInterpolator;
+///
+/// let dimmed = LinearInterpolator::new(0.0..=1.0, &[[255u8, 0, 0], [0, 0, 255]]) * 0.5;
+/// assert_eq!(dimmed.interpolate(0.0), [127, 0, 0]);
+/// ```
+impl<const N: usize, S: Numeric, T: Numeric> core::ops::Mul<f64>
+    for LinearInterpolator<'_, N, S, T>
+{
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        self.scale_output(factor)
+    }
+}
+
+/// Adds a delta, per channel, to every stop's values, as [`LinearInterpolator::offset_output`] does.  
+/// Results that overflow type T saturate to its maximum or lowest value.
+///
+/// ```rust
+/// use lineic::LinearInterpolator;
+///
+/// let lifted = LinearInterpolator::new(0.0..=1.0, &[[0u8, 0, 0], [255, 255, 255]]) + [16, 16, 16];
+/// assert_eq!(lifted.interpolate(0.0), [16, 16, 16]);
+/// assert_eq!(lifted.interpolate(1.0), [255, 255, 255]);
+/// ```
+impl<const N: usize, S: Numeric, T: Numeric> core::ops::Add<[T; N]>
+    for LinearInterpolator<'_, N, S, T>
+{
+    type Output = Self;
+
+    fn add(self, delta: [T; N]) -> Self {
+        self.offset_output(delta)
+    }
+}
+
+/// An inert placeholder, with a single bucket over `ZERO..=ONE` that always produces zeros.  
+/// This matches [`LinearInterpolator::try_new`] with no value sets, and allows interpolators to be used in `#[derive(Default)]` structs.
+///
+/// # Example
+/// ```rust
+/// use lineic::LinearInterpolator;
+///
+/// let interpolator = LinearInterpolator::<3, f32, u8>::default();
+/// assert_eq!(interpolator.interpolate(0.5), [0, 0, 0]);
+/// assert_eq!(interpolator, LinearInterpolator::new(0.0..=1.0, &[]));
+/// ```
+impl<const N: usize, S: Numeric, T: Numeric> Default for LinearInterpolator<'_, N, S, T> {
+    fn default() -> Self {
+        let bucket = InterpolationBucket::new((S::ZERO, S::ONE), [T::ZERO; N], [T::ZERO; N]);
+        Self::from_buckets(vec![bucket])
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> Interpolate<N, S, T>
+    for LinearInterpolator<'_, N, S, T>
+{
+    fn interpolate(&self, s: S) -> [T; N] {
+        LinearInterpolator::interpolate(self, s)
+    }
+}
+
+impl<S: Numeric, T: Numeric> LinearInterpolator<'_, 1, S, T> {
+    /// Compose this interpolator with another, feeding this interpolator's output into `next`'s input.  
+    /// For example, composing `time -> progress` with `progress -> color` produces `time -> color`.
+    ///
+    /// Only single-channel interpolators can be composed, since the output must be usable as the next input.
+    ///
+    /// The result is an approximation, resampled at the union of both interpolators' stops:
+    /// - Every stop of this interpolator
+    /// - Every position in this interpolator's range that maps onto a stop of `next`
+    ///
+    /// The result is exact at each of those positions, and linear between them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let progress = LinearInterpolator::new(0.0..=10.0, &[[0.0], [1.0]]);
+    /// let color = LinearInterpolator::new(0.0..=1.0, &[[255.0, 0.0], [0.0, 255.0]]);
+    ///
+    /// let composed = progress.compose(&color);
+    /// assert_eq!(composed.interpolate(5.0), [127.5, 127.5]);
+    /// ```
+    #[must_use]
+    pub fn compose<const M: usize, U: Numeric>(
+        &self,
+        next: &LinearInterpolator<'_, M, T, U>,
+    ) -> LinearInterpolator<'static, M, S, U> {
+        let mut positions: Vec<S> = self.stops_iter().map(|(s, _)| s).collect();
+        for (t, _) in next.stops_iter() {
+            for bucket in self.buckets() {
+                if let Some(s) = bucket.reverse_interpolate(core::array::from_ref(&t)) {
+                    positions.push(s);
+                }
+            }
+        }
+
+        let reversed = self.is_reversed();
+        positions.sort_by(|a, b| {
+            let order = a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal);
+            if reversed {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+        positions.dedup();
+
+        let value_sets: Vec<_> = positions
+            .iter()
+            .map(|s| {
+                let [t] = self.interpolate(s.clone());
+                next.interpolate(t)
+            })
+            .collect();
+        LinearInterpolator::from_stops(&positions, &value_sets)
+    }
+}
+
+impl<S: Numeric, T: Numeric> LinearInterpolator<'_, 2, S, T> {
+    /// Interpolate between the value sets based on the given value, returning the result as a tuple.  
+    /// Useful for 2D coordinates.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let path = LinearInterpolator::new(0.0..=1.0, &[[0.0, 0.0], [10.0, 20.0]]);
+    /// let (x, y) = path.interpolate_tuple(0.5);
+    /// assert_eq!((x, y), (5.0, 10.0));
+    /// ```
+    pub fn interpolate_tuple(&self, s: S) -> (T, T) {
+        let [a, b] = self.interpolate(s);
+        (a, b)
+    }
+}
+
+impl<S: Numeric, T: Numeric> LinearInterpolator<'_, 3, S, T> {
+    /// Interpolate between the value sets based on the given value, returning the result as a tuple.  
+    /// Useful for 3D coordinates or RGB colors.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let gradient = LinearInterpolator::new(0.0..=1.0, &[[255u8, 0, 0], [0, 0, 255]]);
+    /// let (r, g, b) = gradient.interpolate_tuple(1.0);
+    /// assert_eq!((r, g, b), (0, 0, 255));
+    /// ```
+    pub fn interpolate_tuple(&self, s: S) -> (T, T, T) {
+        let [a, b, c] = self.interpolate(s);
+        (a, b, c)
+    }
+}
+
+impl<S: Numeric> LinearInterpolator<'_, 4, S, u8> {
+    /// Sample the interpolator at `width` evenly spaced positions across the range, including both ends,
+    /// and write the results as interleaved RGBA bytes.  
+    /// The result is ready to upload as a 1D texture, for example with `wgpu` or the `image` crate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let gradient = LinearInterpolator::new(0.0..=1.0, &[[255u8, 0, 0, 255], [0, 0, 255, 0]]);
+    /// assert_eq!(gradient.to_rgba8_texture(3), [255, 0, 0, 255, 128, 0, 127, 128, 0, 0, 255, 0]);
+    /// ```
+    #[must_use]
+    pub fn to_rgba8_texture(&self, width: usize) -> Vec<u8> {
+        self.sample_positions(width)
+            .flat_map(|s| self.interpolate(s))
+            .collect()
+    }
+}
+
+impl<S: Numeric> LinearInterpolator<'_, 3, S, u8> {
+    /// Sample the interpolator at `width` evenly spaced positions across the range, including both ends,
+    /// and write the results as interleaved RGBA bytes, with an opaque alpha channel.  
+    /// The result is ready to upload as a 1D texture, for example with `wgpu` or the `image` crate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let gradient = LinearInterpolator::new(0.0..=1.0, &[[255u8, 0, 0], [0, 0, 255]]);
+    /// assert_eq!(gradient.to_rgba8_texture(2), [255, 0, 0, 255, 0, 0, 255, 255]);
+    /// ```
+    #[must_use]
+    pub fn to_rgba8_texture(&self, width: usize) -> Vec<u8> {
+        self.sample_positions(width)
+            .flat_map(|s| {
+                let [r, g, b] = self.interpolate(s);
+                [r, g, b, u8::MAX]
+            })
+            .collect()
+    }
+}
+
+/// Implements construction from an array of value sets, for an interpolator with a float range.  
+/// Integer ranges have no sensible default domain, so they must use [`LinearInterpolator::new`].
+macro_rules! impl_from_array {
+    ($s:ty) => {
+        impl<const N: usize, const M: usize, T: Numeric> From<[[T; N]; M]>
+            for LinearInterpolator<'_, N, $s, T>
+        {
+            /// Create an interpolator with the given value sets spread evenly across `0.0..=1.0`
+            fn from(value_sets: [[T; N]; M]) -> Self {
+                Self::new(0.0..=1.0, &value_sets)
+            }
+        }
+    };
+}
+
+impl_from_array!(f32);
+impl_from_array!(f64);
+
+/// Implements const interpolation for an interpolator using the same float type for its range and values.
+macro_rules! impl_const_interpolate {
+    ($t:ty) => {
+        impl<const N: usize> LinearInterpolator<'_, N, $t, $t> {
+            /// Interpolate between the value sets based on the given value, in a const context.
+            /// Produces the same result as [`Self::interpolate`], including channel modes and the output clamp.
+            /// The out of range policy is ignored, and inputs outside of the range are always clamped to it.
+            ///
+            /// Const interpolation is only available for interpolators of `f32` ranges and values, or `f64` ranges and values.
+            ///
+            /// # Example
+            /// ```rust
+            /// use lineic::{static_interpolator, LinearInterpolator};
+            ///
+            /// const GRADIENT: &LinearInterpolator<3, f32, f32> = &static_interpolator! {
+            ///     (0.0 => 127.0) [255.0, 0.0, 0.0] => [0.0, 255.0, 0.0];
+            ///     (127.0 => 255.0) [0.0, 255.0, 0.0] => [0.0, 0.0, 255.0];
+            /// };
+            ///
+            /// // A lookup table baked at compile time
+            /// const TABLE: [[f32; 3]; 256] = {
+            ///     let mut table = [[0.0; 3]; 256];
+            ///     let mut i = 0;
+            ///     while i < 256 {
+            ///         table[i] = GRADIENT.interpolate_const(i as f32);
+            ///         i += 1;
+            ///     }
+            ///     table
+            /// };
+            ///
+            /// assert_eq!(TABLE[127], [0.0, 255.0, 0.0]);
+            /// ```
+            #[must_use]
+            pub const fn interpolate_const(&self, s: $t) -> [$t; N] {
+                let buckets: &[InterpolationBucket<N, $t, $t>] = match &self.buckets {
+                    Cow::Borrowed(buckets) => buckets,
+                    Cow::Owned(buckets) => buckets.as_slice(),
+                };
+                if buckets.is_empty() {
+                    return [0.0; N];
+                }
+
+                // Binary search for the bucket that contains the value, as in `get_bucket`
+                let rev = buckets[0].range().start > buckets[0].range().end;
+                let (mut lo, mut hi) = (0, buckets.len());
+                while hi - lo > 1 {
+                    let mid = lo + (hi - lo) / 2;
+                    let range = buckets[mid].range();
+
+                    let (min, max) = if rev {
+                        (range.end, range.start)
+                    } else {
+                        (range.start, range.end)
+                    };
+                    if min <= s && s <= max {
+                        lo = mid;
+                        break;
+                    }
+
+                    if (!rev && s >= range.start) || (rev && s <= range.start) {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let bucket = &buckets[lo];
+
+                let rel_percent = bucket.progress_const(s);
+                let mut values = *bucket.values_lo();
+                let mut i = 0;
+                while i < N {
+                    let step = match &self.channel_modes {
+                        Some(modes) => matches!(modes[i], ChannelMode::Step),
+                        None => false,
+                    };
+
+                    values[i] = if !step {
+                        bucket.lerp_channel_const(i, rel_percent)
+                    } else if rel_percent < 0.5 {
+                        bucket.values_lo()[i]
+                    } else {
+                        bucket.values_hi()[i]
+                    };
+
+                    if let Some((min, max)) = &self.output_clamp {
+                        let (min, max) = if min[i] > max[i] {
+                            (max[i], min[i])
+                        } else {
+                            (min[i], max[i])
+                        };
+
+                        if values[i] < min {
+                            values[i] = min;
+                        } else if values[i] > max {
+                            values[i] = max;
+                        }
+                    }
+
+                    i += 1;
+                }
+
+                values
+            }
+        }
+    };
+}
+
+impl_const_interpolate!(f32);
+impl_const_interpolate!(f64);
+
 /// A macro to create a static linear interpolator.  
 /// This macro is a convenience wrapper around [`LinearInterpolator::new_from_raw`].
 ///
@@ -221,38 +2606,1364 @@ macro_rules! static_interpolator {
     };
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::easing::{EaseInCubic, EaseInOutQuad, EasingMode, Linear};
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    #[allow(clippy::unreadable_literal)]
+    fn test_new() {
+        let interpolator =
+            LinearInterpolator::new(0.0..=100.0, &[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+        assert_eq!(interpolator.buckets().len(), 2);
+        assert_eq!(
+            interpolator.buckets()[0],
+            InterpolationBucket::new(0.0..=50.0, [0.0, 0.0], [1.0, 1.0])
+        );
+        assert_eq!(
+            interpolator.buckets()[1],
+            InterpolationBucket::new(50.0..=100.0, [1.0, 1.0], [2.0, 2.0])
+        );
+
+        let interpolator = LinearInterpolator::new(
+            100.0..=0.0,
+            &[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]],
+        );
+        assert_eq!(interpolator.buckets().len(), 3);
+        assert_eq!(
+            interpolator.buckets()[0],
+            InterpolationBucket::new(100.0..=66.66666666666666, [0.0, 0.0], [1.0, 1.0])
+        );
+
+        let empty = LinearInterpolator::<0, f64, f64>::new(0.0..=0.0, &[]);
+        assert_eq!(empty.interpolate(0.0), []);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_argmax_argmin() {
+        let interpolator = LinearInterpolator::new(0.0..=100.0, &[[0, 9, 5], [7, 2, 5], [3, 9, 1]]);
+        assert_eq!(interpolator.argmax(), [50.0, 0.0, 0.0]);
+        assert_eq!(interpolator.argmin(), [0.0, 50.0, 100.0]);
+
+        // Reversed ranges report positions in the same domain
+        let interpolator = LinearInterpolator::new(100.0..=0.0, &[[0, 9, 5], [7, 2, 5], [3, 9, 1]]);
+        assert_eq!(interpolator.argmax(), [50.0, 100.0, 100.0]);
+        assert_eq!(interpolator.argmin(), [100.0, 50.0, 0.0]);
+    }
+
+    #[test]
+    fn test_interpolate_safe() {
+        let interpolator = LinearInterpolator::new(10.0f32..=0.0, &[[0], [50], [100]]);
+        assert_eq!(interpolator.interpolate_safe(f32::NAN), [0]);
+        assert_eq!(interpolator.interpolate_safe(f32::INFINITY), [0]);
+        assert_eq!(interpolator.interpolate_safe(f32::NEG_INFINITY), [100]);
+        assert_eq!(
+            interpolator.interpolate_safe(5.0),
+            interpolator.interpolate(5.0)
+        );
+
+        let interpolator = interpolator.with_output_clamp([10], [90]);
+        assert_eq!(interpolator.interpolate_safe(f32::NAN), [10]);
+    }
+
+    #[test]
+    fn test_resample() {
+        let value_sets: Vec<_> = (0..=50).map(|i| [i * 2, 100 - i * 2]).collect();
+        let interpolator = LinearInterpolator::new((100u8, 0), &value_sets);
+
+        let resampled = interpolator.resample(5).unwrap();
+        assert_eq!(resampled.buckets().len(), 4);
+        assert_eq!(
+            resampled.buckets()[0],
+            InterpolationBucket::new((100, 75), [0, 100], [25, 75])
+        );
+        assert_eq!(resampled.interpolate(0), [100, 0]);
+
+        assert!(interpolator.resample(1).is_none());
+        assert!(interpolator.resample(300).is_none());
+    }
+
+    #[test]
+    fn test_cast_values() {
+        let interpolator = LinearInterpolator::new(
+            0.0f32..=10.0,
+            &[[255.0f32, 0.0, 0.0], [0.0, 255.0, 0.0], [0.0, 0.0, 255.0]],
+        );
+        let bytes = interpolator.cast_values::<u8>().unwrap();
+        assert_eq!(bytes.buckets().len(), 2);
+        assert_eq!(bytes.interpolate(0.0), [255, 0, 0]);
+        assert_eq!(bytes.interpolate(5.0), [0, 255, 0]);
+        assert_eq!(bytes.interpolate(7.5), [0, 128, 127]);
+
+        let out_of_range = LinearInterpolator::new(0.0..=1.0, &[[-1.0f32], [256.0]]);
+        assert!(out_of_range.cast_values::<u8>().is_none());
+    }
+
+    #[test]
+    fn test_scale_output() {
+        let interpolator = LinearInterpolator::new(0.0..=10.0, &[[200u8, 10], [100, 0], [0, 50]])
+            .with_output_clamp([0, 0], [150, 150]);
+
+        let dimmed = interpolator.scale_output(0.5);
+        assert_eq!(dimmed.value_sets(), vec![[100, 5], [50, 0], [0, 25]]);
+        assert_eq!(dimmed.output_clamp(), interpolator.output_clamp());
+        assert_eq!(dimmed.interpolate(2.5), [75, 3]);
+
+        // Saturates at both ends of the type
+        let scaled = interpolator.scale_output(2.0);
+        assert_eq!(scaled.value_sets(), vec![[255, 20], [200, 0], [0, 100]]);
+        let scaled = LinearInterpolator::new(0.0..=1.0, &[[-100i8], [100]]).scale_output(2.0);
+        assert_eq!(scaled.value_sets(), vec![[-127], [127]]);
+    }
+
+    #[test]
+    fn test_offset_output() {
+        let interpolator = LinearInterpolator::new(0.0..=10.0, &[[200u8, 10], [100, 0], [0, 50]])
+            .with_output_clamp([0, 0], [150, 150]);
+
+        let shifted = interpolator.offset_output([10, 0]);
+        assert_eq!(shifted.value_sets(), vec![[210, 10], [110, 0], [10, 50]]);
+        assert_eq!(shifted.output_clamp(), interpolator.output_clamp());
+        assert_eq!(shifted.interpolate(5.0), [110, 0]);
+
+        // Saturates at both ends of the type
+        let shifted = interpolator.offset_output([100, 250]);
+        assert_eq!(
+            shifted.value_sets(),
+            vec![[255, 255], [200, 250], [100, 255]]
+        );
+        let shifted =
+            LinearInterpolator::new(0.0..=1.0, &[[-100i8, 100], [0, 0]]).offset_output([-50, 50]);
+        assert_eq!(shifted.value_sets(), vec![[-127, 127], [-50, 50]]);
+    }
+
+    #[test]
+    fn test_bake_table() {
+        let interpolator = LinearInterpolator::new(0u8..=255, &[[0u8, 255], [255, 0]]);
+        let table = interpolator.bake_table();
+        assert_eq!(table.len(), 256);
+        for (s, values) in (0..=255).zip(&table) {
+            assert_eq!(*values, interpolator.interpolate(s));
+        }
+
+        // Reversed ranges are still stored in ascending order of s
+        let interpolator = LinearInterpolator::new((20i8, -20), &[[0i8], [40]]);
+        let table = interpolator.bake_table();
+        assert_eq!(table.len(), 41);
+        assert_eq!(table[0], [40]);
+        assert_eq!(table[40], [0]);
+    }
+
+    #[test]
+    fn test_relative_eq() {
+        let a = LinearInterpolator::new(0.0..=1.0, &[[0.0, 1000.0], [0.3, 2000.0]]);
+        let b = LinearInterpolator::new(0.0..=1.0, &[[0.0, 1000.0], [0.1 + 0.2, 2000.1]]);
+        assert!(!a.abs_diff_eq(&b, 1e-6));
+        assert!(a.abs_diff_eq(&b, 0.2));
+        assert!(a.relative_eq(&b, 1e-6, 1e-4));
+        assert!(!a.relative_eq(&b, 1e-6, 1e-6));
+
+        // Settings must match too
+        let clamped = a.clone().with_output_clamp([0.0, 0.0], [1.0, 1.0]);
+        assert!(!a.abs_diff_eq(&clamped, 1.0));
+        assert!(clamped.abs_diff_eq(&clamped.clone(), 0.0));
+
+        // As must the layout of the buckets
+        let resampled = a.resample(3).unwrap();
+        assert!(!a.abs_diff_eq(&resampled, 1.0));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            LinearInterpolator::new((100u8, 0), &[[0u8], [1], [2], [3]]).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            LinearInterpolator::<1, u8, u8>::new_from_raw(&[]).validate(),
+            Err(InterpolatorError::NoBuckets)
+        );
+
+        let misoriented = [
+            InterpolationBucket::new((10u8, 5), [0u8], [1]),
+            InterpolationBucket::new((5, 5), [1], [1]),
+            InterpolationBucket::new((5, 8), [1], [2]),
+        ];
+        assert_eq!(
+            LinearInterpolator::new_from_raw(&misoriented).validate(),
+            Err(InterpolatorError::Misoriented { index: 2 })
+        );
+
+        let discontinuous = [
+            InterpolationBucket::new((0u8, 5), [0u8], [1]),
+            InterpolationBucket::new((5, 10), [1], [2]),
+            InterpolationBucket::new((11, 20), [2], [3]),
+        ];
+        assert_eq!(
+            LinearInterpolator::new_from_raw(&discontinuous).validate(),
+            Err(InterpolatorError::Discontinuous { index: 1 })
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_from_array() {
+        let interpolator: crate::interpolators::F32LinearInterpolator<3> =
+            [[255.0, 0.0, 0.0], [0.0, 255.0, 0.0], [0.0, 0.0, 255.0]].into();
+        assert_eq!(interpolator.interpolate(0.0), [255.0, 0.0, 0.0]);
+        assert_eq!(interpolator.interpolate(0.5), [0.0, 255.0, 0.0]);
+        assert_eq!(interpolator.interpolate(1.0), [0.0, 0.0, 255.0]);
+
+        let interpolator = LinearInterpolator::<1, f64, u8>::from([[0], [100]]);
+        assert_eq!(interpolator.interpolate(0.25), [25]);
+    }
+
+    #[test]
+    fn test_nearest_stop() {
+        let palette =
+            LinearInterpolator::new((30u8, 0), &[[0i16, 0], [100, 0], [0, 100], [100, 100]]);
+        assert_eq!(palette.nearest_stop(&[0, 0]), ([0, 0], 30));
+        assert_eq!(palette.nearest_stop(&[90, 20]), ([100, 0], 20));
+        assert_eq!(palette.nearest_stop(&[-50, 500]), ([0, 100], 10));
+        assert_eq!(palette.nearest_stop(&[1000, 1000]), ([100, 100], 0));
+
+        // Ties resolve towards the start
+        assert_eq!(palette.nearest_stop(&[50, 0]), ([0, 0], 30));
+
+        // NaN distances never compare as nearer
+        let palette = LinearInterpolator::new(0.0..=1.0, &[[1.0], [2.0]]);
+        assert_eq!(palette.nearest_stop(&[f64::NAN]), ([0.0], 0.0));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_compose() {
+        let progress = LinearInterpolator::new(0.0..=10.0, &[[0.0], [0.75], [1.0]]);
+        let color = LinearInterpolator::new(0.0..=1.0, &[[0.0, 0.0], [100.0, 0.0], [0.0, 10.0]]);
+
+        // Stops at 0, 5 and 10 from `progress`, and 0.5 maps back to 3.33..
+        let composed = progress.compose(&color);
+        assert_eq!(composed.buckets().len(), 3);
+        assert_eq!(composed.interpolate(0.0), [0.0, 0.0]);
+        assert_eq!(composed.interpolate(5.0), [50.0, 5.0]);
+        assert_eq!(composed.interpolate(10.0), [0.0, 10.0]);
+
+        let peak = composed.buckets()[0].end();
+        assert!((peak - 10.0 / 3.0).abs() < 1e-9);
+        assert_eq!(composed.interpolate(peak), [100.0, 0.0]);
+
+        // Orientation follows the first interpolator
+        let reversed = LinearInterpolator::new(10.0..=0.0, &[[0.0], [0.75], [1.0]]);
+        let composed = reversed.compose(&color);
+        assert!(composed.is_reversed());
+        assert_eq!(composed.interpolate(10.0), [0.0, 0.0]);
+        assert_eq!(composed.interpolate(0.0), [0.0, 10.0]);
+    }
+
+    #[test]
+    fn test_from_cdf() {
+        let cdf = [0.0, 0.1, 0.5, 1.0];
+        let values = [[0u8], [85], [170], [255]];
+
+        let interpolator = LinearInterpolator::from_cdf(0.0..=200.0, &cdf, &values).unwrap();
+        assert_eq!(interpolator.buckets().len(), 3);
+        assert_eq!(
+            interpolator.buckets()[1],
+            InterpolationBucket::new(20.0..=100.0, [85], [170])
+        );
+
+        let interpolator = LinearInterpolator::from_cdf(200.0..=0.0, &cdf, &values).unwrap();
+        assert_eq!(interpolator.interpolate(180.0), [85]);
+
+        assert!(LinearInterpolator::from_cdf(0.0..=1.0, &[0.0, 0.5], &values).is_none());
+        assert!(LinearInterpolator::from_cdf(0.0..=1.0, &[0.0, 0.6, 0.5, 1.0], &values).is_none());
+        assert!(LinearInterpolator::from_cdf(0.0..=1.0, &[0.0, 0.5, 1.0, 1.5], &values).is_none());
+        assert!(LinearInterpolator::<1, f64, u8>::from_cdf(0.0..=1.0, &[], &[]).is_none());
+    }
+
+    #[test]
+    fn test_new_with_holds() {
+        let values = [[0u8], [100], [200]];
+        let interpolator = LinearInterpolator::new_with_holds(0.0..=100.0, &values, 0.4).unwrap();
+        assert_eq!(interpolator.buckets().len(), 5);
+        assert_eq!(
+            interpolator.buckets()[0],
+            InterpolationBucket::new(0.0..=10.0, [0], [0])
+        );
+        assert_eq!(
+            interpolator.buckets()[2],
+            InterpolationBucket::new(40.0..=60.0, [100], [100])
+        );
+        assert_eq!(interpolator.interpolate(5.0), [0]);
+        assert_eq!(interpolator.interpolate(25.0), [50]);
+        assert_eq!(interpolator.interpolate(55.0), [100]);
+        assert_eq!(interpolator.interpolate(95.0), [200]);
+
+        // Without holds, this is the same as `new`
+        let interpolator = LinearInterpolator::new_with_holds((100u8, 0), &values, 0.0).unwrap();
+        assert_eq!(interpolator, LinearInterpolator::new((100u8, 0), &values));
+
+        assert!(LinearInterpolator::new_with_holds(0.0..=1.0, &values, 1.0).is_none());
+        assert!(LinearInterpolator::new_with_holds(0.0..=1.0, &values, -0.1).is_none());
+        assert!(LinearInterpolator::new_with_holds(0.0..=1.0, &values, f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_interpolate_with_easing() {
+        let interpolator = LinearInterpolator::new_with_modes(
+            (10u8, 0),
+            &[[0u8, 0], [200, 1]],
+            [ChannelMode::Continuous, ChannelMode::Step],
+        )
+        .with_output_clamp([0, 0], [150, 1]);
+
+        assert_eq!(interpolator.interpolate_with_easing(10, &Linear), [0, 0]);
+        assert_eq!(
+            interpolator.interpolate_with_easing(5, &EaseInCubic),
+            [25, 1]
+        );
+        assert_eq!(
+            interpolator.interpolate_with_easing(4, &EaseInOutQuad),
+            [136, 1]
+        );
+        assert_eq!(
+            interpolator.interpolate_with_easing(0, &EaseInCubic),
+            [150, 1]
+        );
+
+        // Eased values outside of 0.0..=1.0 do not overshoot the stops
+        let overshoot = |t: f64| t * 2.0 - 0.5;
+        assert_eq!(interpolator.interpolate_with_easing(9, &overshoot), [0, 0]);
+        assert_eq!(
+            interpolator.interpolate_with_easing(1, &overshoot),
+            [150, 1]
+        );
+    }
+
+    #[test]
+    fn test_concat() {
+        let first = LinearInterpolator::new((100u8, 50), &[[0u8, 255], [100, 0], [200, 255]]);
+        let second = LinearInterpolator::new((50u8, 0), &[[200u8, 255], [0, 0], [50, 50]]);
+
+        let joined = first.concat(&second).unwrap();
+        assert_eq!(joined.buckets().len(), 4);
+        assert_eq!(&joined.buckets()[..2], first.buckets());
+        assert_eq!(&joined.buckets()[2..], second.buckets());
+        assert!(joined.validate().is_ok());
+
+        for s in 0..=100 {
+            let expected = if s >= 50 {
+                first.interpolate(s)
+            } else {
+                second.interpolate(s)
+            };
+            assert_eq!(joined.interpolate(s), expected);
+        }
+
+        // Gaps, and mismatched orientations, cannot be joined
+        assert!(second.concat(&first).is_none());
+        let forward = LinearInterpolator::new((50u8, 60), &[[200u8, 255], [0, 0]]);
+        assert!(first.concat(&forward).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_integrate() {
+        let interpolator = LinearInterpolator::new((20u8, 0), &[[0u8, 10], [100, 10], [50, 0]]);
+        assert_eq!(interpolator.integrate_channel(0), 1250.0);
+        assert_eq!(interpolator.integrate_channel(1), 150.0);
+        assert_eq!(interpolator.integrate(), [1250.0, 150.0]);
+
+        // Orientation does not affect the area
+        let forward = LinearInterpolator::new(0u8..=20, &[[0u8, 10], [100, 10], [50, 0]]);
+        assert_eq!(forward.integrate(), interpolator.integrate());
+
+        let empty = LinearInterpolator::<2, f64, f64>::new(0.0..=1.0, &[]);
+        assert_eq!(empty.integrate(), [0.0, 0.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_quantize_output() {
+        let interpolator = LinearInterpolator::new((10u8, 0), &[[0i8, 0, 0], [-100, 100, 127]]);
+        assert_eq!(interpolator.quantize_output(10, [25, 25, 25]), [0, 0, 0]);
+        assert_eq!(interpolator.quantize_output(6, [25, 25, 25]), [-50, 50, 50]);
+        assert_eq!(interpolator.quantize_output(4, [-25, 0, 25]), [-50, 60, 75]);
+
+        // 150 is the nearest multiple, but 100 is the nearest one that fits
+        assert_eq!(
+            interpolator.quantize_output(0, [25, 25, 50]),
+            [-100, 100, 100]
+        );
+
+        let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0.0], [1.0]]);
+        assert_eq!(interpolator.quantize_output(0.4, [0.25]), [0.5]);
+        assert_eq!(interpolator.quantize_output(0.4, [0.0]), [0.4]);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let interpolator = LinearInterpolator::new((10i8, 0), &[[0i8, 0], [50, 0], [100, -100]]);
+        assert_eq!(interpolator.out_of_range(), OutOfRange::Clamp);
+        assert_eq!(interpolator.interpolate(-5), [100, -100]);
+
+        let saturating = interpolator.clone().with_out_of_range(OutOfRange::Saturate);
+        assert_eq!(saturating.interpolate(5), interpolator.interpolate(5));
+        assert_eq!(saturating.interpolate(-5), [127, -127]);
+        assert_eq!(saturating.interpolate(-1), [110, -120]);
+        assert_eq!(saturating.interpolate(12), [-20, 0]);
+
+        // The policy carries over to derived interpolators
+        assert_eq!(
+            saturating.resample(5).unwrap().out_of_range(),
+            OutOfRange::Saturate
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[allow(clippy::float_cmp)]
+    #[should_panic(expected = "outside of the interpolator's range")]
+    fn test_out_of_range_panic() {
+        let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0.0], [1.0]])
+            .with_out_of_range(OutOfRange::Panic);
+        assert_eq!(interpolator.interpolate(1.0), [1.0]);
+        interpolator.interpolate(1.5);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_domain_progress() {
+        let interpolator = LinearInterpolator::new((200u8, 100), &[[0u8], [10], [20]]);
+        assert_eq!(interpolator.domain_progress(200), 0.0);
+        assert_eq!(interpolator.domain_progress(175), 0.25);
+        assert_eq!(interpolator.domain_progress(110), 0.9);
+        assert_eq!(interpolator.domain_progress(0), 1.0);
+        assert_eq!(interpolator.domain_progress(255), 0.0);
+
+        // Unlike the bucket's progress, this spans the whole range
+        assert_eq!(interpolator.get_bucket(175).progress(175), 0.5);
+        assert_eq!(interpolator.get_bucket(110).progress(110), 0.8);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_blend_indices() {
+        let value_sets = [[0u8, 255], [100, 0], [200, 100]];
+        let interpolator = LinearInterpolator::new((10u8, 0), &value_sets);
+        assert_eq!(interpolator.blend_indices(10), (0, 1, 0.0));
+        assert_eq!(interpolator.blend_indices(8), (0, 1, 0.4));
+        assert_eq!(interpolator.blend_indices(0), (1, 2, 1.0));
+        assert_eq!(interpolator.blend_indices(20), (0, 1, 0.0));
+
+        // Blending the stops on the caller's side matches interpolate
+        let stops = interpolator.value_sets();
+        for s in 0..=10 {
+            let (lo, hi, weight) = interpolator.blend_indices(s);
+            let blended = InterpolationBucket::new(0.0..=1.0, stops[lo], stops[hi]);
+            assert_eq!(
+                blended.interpolate(f64::from(weight)),
+                interpolator.interpolate(s)
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_rgba8_texture() {
+        let stops = [[255u8, 0, 0, 255], [0, 255, 0, 128], [0, 0, 255, 0]];
+        let gradient = LinearInterpolator::new((100u16, 0), &stops);
+        let texture = gradient.to_rgba8_texture(256);
+        assert_eq!(texture.len(), 256 * 4);
+        assert_eq!(texture[..4], stops[0]);
+        assert_eq!(texture[texture.len() - 4..], stops[2]);
+
+        let rgb = LinearInterpolator::new((100u16, 0), &[[255u8, 0, 0], [0, 0, 255]]);
+        let texture = rgb.to_rgba8_texture(16);
+        assert_eq!(texture.len(), 16 * 4);
+        assert_eq!(texture[..4], [255, 0, 0, 255]);
+        assert_eq!(texture[texture.len() - 4..], [0, 0, 255, 255]);
+        assert!(texture.chunks(4).all(|pixel| pixel[3] == 255));
+
+        assert!(rgb.to_rgba8_texture(0).is_empty());
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let samples: Vec<_> = (0..10u8).map(|i| [i, 9 - i]).collect();
+
+        let interpolator = LinearInterpolator::from_samples((1.0, 0.0), &samples, 4).unwrap();
+        assert_eq!(interpolator.value_sets(), [[0, 9], [3, 6], [6, 3], [9, 0]]);
+        assert!(interpolator.is_reversed());
+
+        // Picks are rounded to the nearest sample
+        let interpolator = LinearInterpolator::from_samples(0u8..=10, &samples, 3).unwrap();
+        assert_eq!(interpolator.value_sets(), [[0, 9], [5, 4], [9, 0]]);
+
+        let interpolator = LinearInterpolator::from_samples(0u8..=10, &samples, 10).unwrap();
+        assert_eq!(interpolator.value_sets(), samples);
+
+        assert!(LinearInterpolator::from_samples(0u8..=10, &samples, 1).is_none());
+        assert!(LinearInterpolator::from_samples(0u8..=10, &samples, 11).is_none());
+    }
+
+    #[test]
+    fn test_channel_monotonicity() {
+        let interpolator = LinearInterpolator::new(
+            0.0..=1.0,
+            &[[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 0.0, f64::NAN]],
+        );
+        assert_eq!(
+            interpolator.channel_monotonicity(),
+            [
+                Monotonicity::Increasing,
+                Monotonicity::Constant,
+                Monotonicity::NonMonotonic
+            ]
+        );
+
+        // Jumps between discontinuous buckets are taken into account
+        let buckets = [
+            InterpolationBucket::new((0, 1), [0u8], [10]),
+            InterpolationBucket::new((1, 2), [5], [20]),
+        ];
+        let interpolator = LinearInterpolator::new_from_raw(&buckets);
+        assert_eq!(
+            interpolator.channel_monotonicity(),
+            [Monotonicity::NonMonotonic]
+        );
+
+        let empty = LinearInterpolator::<1, u8, u8>::new_from_raw(&[]);
+        assert_eq!(empty.channel_monotonicity(), [Monotonicity::Constant]);
+    }
+
+    #[test]
+    fn test_tabulate() {
+        let interpolator = LinearInterpolator::new((100u8, 0), &[[0u8, 200], [100, 100], [200, 0]]);
+        let table = interpolator.tabulate(5);
+        assert_eq!(
+            table,
+            [
+                (100, [0, 200]),
+                (75, [50, 150]),
+                (50, [100, 100]),
+                (25, [150, 50]),
+                (0, [200, 0])
+            ]
+        );
+
+        assert_eq!(interpolator.tabulate(1), [(100, [0, 200])]);
+        assert!(interpolator.tabulate(0).is_empty());
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let owned = {
+            let buckets = [
+                InterpolationBucket::new((10u8, 5), [0u8], [50]),
+                InterpolationBucket::new((5u8, 0), [50u8], [100]),
+            ];
+            let borrowed = LinearInterpolator::new_from_raw(&buckets)
+                .with_output_clamp([10], [90])
+                .with_out_of_range(OutOfRange::Saturate);
+            let owned = borrowed.clone().into_owned();
+            assert_eq!(owned, borrowed);
+            owned
+        };
+
+        assert_eq!(owned.interpolate(0), [90]);
+        assert_eq!(owned.output_clamp(), Some(&([10], [90])));
+        assert_eq!(owned.out_of_range(), OutOfRange::Saturate);
+    }
+
+    #[test]
+    fn test_from_iter_stops() {
+        let stops = (0..5u8).filter(|i| i % 2 == 0).map(|i| [i * 10, 100 - i]);
+        let interpolator = LinearInterpolator::from_iter_stops((100u8, 0), stops).unwrap();
+        assert_eq!(
+            interpolator,
+            LinearInterpolator::new((100u8, 0), &[[0, 100], [20, 98], [40, 96]])
+        );
+
+        let empty = LinearInterpolator::<1, u8, u8>::from_iter_stops((0, 10), []).unwrap();
+        assert_eq!(empty, LinearInterpolator::new((0, 10), &[]));
+
+        let too_many = (0..300).map(|_| [0u8]);
+        assert!(LinearInterpolator::from_iter_stops((0u8, 10), too_many).is_none());
+    }
+
+    #[test]
+    fn test_interpolate_per_channel_easing() {
+        let interpolator = LinearInterpolator::new_with_modes(
+            (10u8, 0),
+            &[[0u8, 0, 0], [200, 200, 1]],
+            [
+                ChannelMode::Continuous,
+                ChannelMode::Continuous,
+                ChannelMode::Step,
+            ],
+        )
+        .with_output_clamp([0, 0, 0], [150, 255, 1]);
+
+        let easings = [
+            EasingMode::EaseInCubic,
+            EasingMode::Linear,
+            EasingMode::EaseInCubic,
+        ];
+        assert_eq!(
+            interpolator.interpolate_per_channel_easing(10, &easings),
+            [0, 0, 0]
+        );
+        assert_eq!(
+            interpolator.interpolate_per_channel_easing(5, &easings),
+            [25, 100, 1]
+        );
+        assert_eq!(
+            interpolator.interpolate_per_channel_easing(0, &easings),
+            [150, 200, 1]
+        );
+
+        // All-linear easing is the same as plain interpolation
+        let linear = [EasingMode::default(); 3];
+        for s in 0..=10 {
+            assert_eq!(
+                interpolator.interpolate_per_channel_easing(s, &linear),
+                interpolator.interpolate(s)
+            );
+        }
+    }
+
+    #[test]
+    fn test_bucket_as_interpolator() {
+        let interpolator = LinearInterpolator::new((30u8, 0), &[[0u8], [100], [200], [250]])
+            .with_output_clamp([0], [240]);
+
+        for (i, bucket) in interpolator.buckets().iter().enumerate() {
+            let segment = interpolator.bucket_as_interpolator(i).unwrap();
+            assert_eq!(segment.buckets(), core::slice::from_ref(bucket));
+            assert_eq!(segment.output_clamp(), interpolator.output_clamp());
+            assert!(segment.validate().is_ok());
+        }
+
+        let segment = interpolator.bucket_as_interpolator(2).unwrap();
+        assert_eq!(segment.interpolate(5), interpolator.interpolate(5));
+        assert_eq!(segment.interpolate(30), [200]);
+        assert!(interpolator.bucket_as_interpolator(3).is_none());
+    }
+
+    #[test]
+    fn test_try_new_checked() {
+        let rows: [&[u8]; 3] = [&[0, 100], &[50, 50], &[100, 0]];
+        let interpolator = LinearInterpolator::<2, u8, u8>::try_new_checked((0, 20), &rows);
+        assert_eq!(
+            interpolator,
+            Ok(LinearInterpolator::new(
+                (0, 20),
+                &[[0, 100], [50, 50], [100, 0]]
+            ))
+        );
+
+        let rows: [&[u8]; 3] = [&[0, 100], &[50, 50, 50], &[100]];
+        assert_eq!(
+            LinearInterpolator::<2, u8, u8>::try_new_checked((0, 20), &rows),
+            Err(InterpolatorError::ValueSetLength {
+                index: 1,
+                len: 3,
+                expected: 2
+            })
+        );
+
+        let rows = vec![[0u8].as_slice(); 300];
+        assert_eq!(
+            LinearInterpolator::<1, u8, u8>::try_new_checked((0, 10), &rows),
+            Err(InterpolatorError::TooManyValueSets)
+        );
+    }
+
+    #[test]
+    fn test_semantically_eq() {
+        let forward = LinearInterpolator::new((0u8, 20), &[[0i16, 10], [50, -10], [100, 0]]);
+        let reversed = LinearInterpolator::new((20u8, 0), &[[100i16, 0], [50, -10], [0, 10]]);
+        assert_ne!(forward, reversed);
+        assert!(forward.semantically_eq(&reversed));
+        assert!(reversed.semantically_eq(&forward));
+        assert!(forward.semantically_eq(&forward));
+
+        // Reversing the range alone changes the mapping
+        let flipped = LinearInterpolator::new((20u8, 0), &[[0i16, 10], [50, -10], [100, 0]]);
+        assert!(!forward.semantically_eq(&flipped));
+
+        // Settings still have to match
+        let clamped = reversed.clone().with_output_clamp([0, 0], [50, 50]);
+        assert!(!forward.semantically_eq(&clamped));
+    }
+
+    #[test]
+    fn test_smooth_stops() {
+        let interpolator = LinearInterpolator::new(
+            (40u8, 0),
+            &[[0i16, 10], [0, 10], [90, -20], [0, 10], [0, 10]],
+        )
+        .with_output_clamp([0, 0], [20, 20]);
+
+        let smoothed = interpolator.smooth_stops(3);
+        assert_eq!(
+            smoothed.value_sets(),
+            vec![[0, 10], [30, 0], [30, 0], [30, 0], [0, 10]]
+        );
+        assert_eq!(smoothed.buckets().len(), interpolator.buckets().len());
+        assert_eq!(smoothed.domain(), interpolator.domain());
+        assert_eq!(smoothed.output_clamp(), interpolator.output_clamp());
+
+        // An even window is widened, reaching the middle stop from either end
+        assert_eq!(interpolator.smooth_stops(4).value_sets(), vec![[18, 4]; 5]);
+
+        assert_eq!(interpolator.smooth_stops(0), interpolator);
+        assert_eq!(interpolator.smooth_stops(1), interpolator);
+    }
+
+    #[test]
+    fn test_get_bucket_mut() {
+        const BORROWED: LinearInterpolator<1, i8, u8> = crate::static_interpolator! {
+            (-10 => 0) [0] => [100];
+            (0 => 10) [100] => [200];
+        };
+
+        let mut interpolator = BORROWED;
+        let bucket = interpolator.get_bucket_mut(5).unwrap();
+        *bucket.values_hi_mut() = [0];
+        assert_eq!(interpolator.interpolate(10), [0]);
+        assert_eq!(BORROWED.interpolate(10), [200]);
+
+        // Editing a range is allowed, but can break contiguity
+        interpolator.buckets_mut()[0] = InterpolationBucket::new((-10, -5), [0], [100]);
+        assert_eq!(
+            interpolator.validate(),
+            Err(InterpolatorError::Discontinuous { index: 0 })
+        );
+
+        let mut empty = LinearInterpolator::<1, i8, u8>::from_buckets(vec![]);
+        assert!(empty.get_bucket_mut(0).is_none());
+        assert!(empty.buckets_mut().is_empty());
+    }
+
+    #[test]
+    fn test_dominant_channel() {
+        let interpolator = LinearInterpolator::new((10u8, 0), &[[0i8, 50, -10], [40, 50, 90]]);
+        assert_eq!(interpolator.dominant_channel(10), 1);
+        assert_eq!(interpolator.dominant_channel(5), 1);
+        assert_eq!(interpolator.dominant_channel(0), 2);
+
+        // Ties resolve to the lowest index
+        assert_eq!(interpolator.dominant_channel(4), 1);
+        let flat = LinearInterpolator::new((0u8, 10), &[[7i8, 7, 7]]);
+        assert_eq!(flat.dominant_channel(5), 0);
+    }
+
+    #[test]
+    fn test_interpolate_many() {
+        let interpolator =
+            LinearInterpolator::new((100u8, 0), &[[0i16, 10], [50, -10], [100, 0], [-20, 5]])
+                .with_output_clamp([-10, -10], [90, 90]);
+
+        // Sorted with the range, against it, and unsorted
+        let inputs: [&[u8]; 3] = [
+            &[200, 100, 70, 66, 34, 33, 10, 0],
+            &[0, 50, 100],
+            &[40, 90, 5, 40],
+        ];
+        for inputs in inputs {
+            let expected: Vec<_> = inputs
+                .iter()
+                .map(|s| interpolator.interpolate(*s))
+                .collect();
+            assert_eq!(interpolator.interpolate_many(inputs), expected);
+        }
+
+        assert!(interpolator.interpolate_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Layer<'a> {
+            gradient: LinearInterpolator<'a, 2, u8, i16>,
+        }
+
+        let layer = Layer::default();
+        assert_eq!(layer.gradient, LinearInterpolator::new((0, 1), &[]));
+        assert_eq!(layer.gradient.interpolate(100), [0, 0]);
+        assert_eq!(layer.gradient.validate(), Ok(()));
+    }
 
     #[test]
     #[allow(clippy::float_cmp)]
-    #[allow(clippy::unreadable_literal)]
-    fn test_new() {
+    fn test_slopes() {
+        let interpolator = LinearInterpolator::new((100u8, 0), &[[0i8, 10], [50, 10], [-50, 0]]);
+        assert_eq!(interpolator.slopes(), vec![[-1.0, 0.0], [2.0, 0.2]]);
+
+        let held = LinearInterpolator::new_with_holds(0.0..=10.0, &[[0.0], [10.0]], 0.5).unwrap();
+        let slopes = held.slopes();
+        assert_eq!(slopes.len(), held.buckets().len());
+        assert!(slopes.iter().all(|[slope]| slope.is_finite()));
+    }
+
+    #[test]
+    fn test_from_keyframes() {
+        let frames = [([0u8, 255], 10u8), ([100, 0], 30), ([200, 100], 255)];
+        let interpolator = LinearInterpolator::from_keyframes(20, &frames).unwrap();
+        assert_eq!(
+            interpolator.buckets(),
+            [
+                InterpolationBucket::new((20, 30), [0, 255], [100, 0]),
+                InterpolationBucket::new((30, 60), [100, 0], [200, 100]),
+            ]
+        );
+        assert_eq!(interpolator.interpolate(45), [150, 50]);
+
+        // Negative durations run the timeline backwards, but cannot be mixed with positive ones
+        let reversed =
+            LinearInterpolator::from_keyframes(0i8, &[([0u8], -10), ([100], 0)]).unwrap();
+        assert!(reversed.is_reversed());
+        assert!(
+            LinearInterpolator::from_keyframes(0i8, &[([0u8], -10), ([100], 5), ([0], 0)])
+                .is_none()
+        );
+
+        // A single frame holds its values
+        let held = LinearInterpolator::from_keyframes(5u8, &[([42u8], 100)]).unwrap();
+        assert_eq!(held.interpolate(0), [42]);
+
+        assert!(LinearInterpolator::<1, u8, u8>::from_keyframes(0, &[]).is_none());
+        assert!(LinearInterpolator::from_keyframes(200u8, &[([0u8], 100), ([1], 0)]).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_arc_length() {
+        let path =
+            LinearInterpolator::new((30u8, 0), &[[0i8, 0, 0], [2, 3, 6], [2, 3, 6], [-10, 3, 1]]);
+        assert_eq!(path.arc_length(), 20.0);
+
+        assert_eq!(path.arc_length_at(30), 0.0);
+        assert_eq!(path.arc_length_at(25), 3.5);
+        assert_eq!(path.arc_length_at(20), 7.0);
+        assert_eq!(path.arc_length_at(15), 7.0);
+        assert_eq!(path.arc_length_at(5), 13.5);
+        assert_eq!(path.arc_length_at(0), 20.0);
+        assert_eq!(path.arc_length_at(200), 0.0);
+
+        let empty = LinearInterpolator::<1, u8, u8>::from_buckets(vec![]);
+        assert_eq!(empty.arc_length(), 0.0);
+        assert_eq!(empty.arc_length_at(5), 0.0);
+    }
+
+    #[test]
+    fn test_interpolate_by_arc_length() {
+        let path = LinearInterpolator::new(3.0..=0.0, &[[0i16, 0], [6, 8], [6, 8], [36, 48]]);
+        assert_eq!(path.interpolate_by_arc_length(0.0), [0, 0]);
+        assert_eq!(path.interpolate_by_arc_length(0.125), [4, 6]);
+        assert_eq!(path.interpolate_by_arc_length(0.5), [18, 24]);
+        assert_eq!(path.interpolate_by_arc_length(1.0), [36, 48]);
+        assert_eq!(path.interpolate_by_arc_length(-1.0), [0, 0]);
+        assert_eq!(path.interpolate_by_arc_length(2.0), [36, 48]);
+
+        // Settings still apply
+        let clamped = path.with_output_clamp([0, 0], [10, 10]);
+        assert_eq!(clamped.interpolate_by_arc_length(0.5), [10, 10]);
+
+        let still = LinearInterpolator::new((0u8, 10), &[[5u8], [5]]);
+        assert_eq!(still.interpolate_by_arc_length(0.5), [5]);
+    }
+
+    #[test]
+    fn test_interpolate_at_stop_fraction() {
         let interpolator =
-            LinearInterpolator::new(0.0..=100.0, &[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
-        assert_eq!(interpolator.buckets().len(), 2);
+            LinearInterpolator::from_soa(&[100u32, 60, 0], &[[0u8, 200], [100, 100], [200, 0]])
+                .unwrap();
+        assert_eq!(interpolator.interpolate_at_stop_fraction(0.0), [0, 200]);
+        assert_eq!(interpolator.interpolate_at_stop_fraction(0.5), [50, 150]);
+        assert_eq!(interpolator.interpolate_at_stop_fraction(1.0), [100, 100]);
+        assert_eq!(interpolator.interpolate_at_stop_fraction(1.25), [125, 75]);
+        assert_eq!(interpolator.interpolate_at_stop_fraction(2.0), [200, 0]);
+
+        // Out of range and NaN fractions are clamped
+        assert_eq!(interpolator.interpolate_at_stop_fraction(-1.0), [0, 200]);
+        assert_eq!(interpolator.interpolate_at_stop_fraction(9.0), [200, 0]);
         assert_eq!(
-            interpolator.buckets()[0],
-            InterpolationBucket::new(0.0..=50.0, [0.0, 0.0], [1.0, 1.0])
+            interpolator.interpolate_at_stop_fraction(f64::NAN),
+            [0, 200]
         );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_normalize_values() {
+        let interpolator = LinearInterpolator::new((10u8, 0), &[[-50i32, 7], [150, 7], [50, 7]]);
+        assert_eq!(interpolator.channel_bounds(), [(-50.0, 150.0), (7.0, 7.0)]);
+
+        // Constant channels become zero
+        let normalized = interpolator.normalize_values();
+        assert_eq!(normalized.interpolate(10), [0.0, 0.0]);
+        assert_eq!(normalized.interpolate(5), [1.0, 0.0]);
+        assert_eq!(normalized.interpolate(0), [0.5, 0.0]);
+
+        // The output clamp is rescaled with the values
+        let clamped = interpolator.with_output_clamp([0, 0], [100, 10]);
+        let normalized = clamped.normalize_values();
+        assert_eq!(normalized.output_clamp(), Some(&([0.25, 0.0], [0.75, 0.0])));
+        assert_eq!(normalized.interpolate(10), [0.25, 0.0]);
+
+        // Normalization can be undone from the bounds
+        let [(min, max), _] = clamped.channel_bounds();
+        assert_eq!(min + normalized.interpolate(0)[0] * (max - min), 50.0);
+
+        let empty = LinearInterpolator::<2, u8, u8>::new_from_raw(&[]);
+        assert_eq!(empty.channel_bounds(), [(0.0, 0.0); 2]);
+    }
+
+    #[test]
+    fn test_kinks() {
+        let interpolator = LinearInterpolator::from_soa(
+            &[0u8, 10, 20, 20, 30, 40],
+            &[[0i32, 5], [10, 5], [20, 5], [30, 5], [40, 5], [50, 6]],
+        )
+        .unwrap();
+
+        // The jump at 20 is a kink on both sides, and the last bucket turns in the second channel
+        assert_eq!(interpolator.kinks(0.05), vec![2, 3, 4]);
+        assert_eq!(interpolator.kinks(0.5), vec![2, 3]);
+
+        let straight = LinearInterpolator::new((100u8, 0), &[[0u8], [50], [100]]);
+        assert!(straight.kinks(0.0).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_reverse_interpolate_scored() {
+        let interpolator =
+            LinearInterpolator::new((16u32, 0), &[[0i32, 0, 9], [64, -64, 9], [0, 64, 9]]);
+
+        // Exact matches agree with the strict lookup
+        for values in [[32, -32, 9], [0, 0, 9], [64, -64, 9], [32, 0, 9]] {
+            let (s, confidence) = interpolator.reverse_interpolate_scored(&values).unwrap();
+            assert_eq!(Some(s), interpolator.reverse_interpolate(&values));
+            assert_eq!(confidence, 1.0);
+        }
+
+        // Noisy values settle on the bucket they fit best
         assert_eq!(
-            interpolator.buckets()[1],
-            InterpolationBucket::new(50.0..=100.0, [1.0, 1.0], [2.0, 2.0])
+            interpolator.reverse_interpolate_scored(&[16, -24, 9]),
+            Some((14, 0.9375))
+        );
+
+        // A constant channel that does not match counts as a full mismatch
+        assert_eq!(
+            interpolator.reverse_interpolate_scored(&[16, -24, 0]),
+            Some((14, 0.0))
+        );
+        assert_eq!(
+            interpolator.reverse_interpolate_scored(&[32, -32, 10]),
+            Some((12, 0.0))
+        );
+
+        // Values outside of every bucket are clamped into the closest fit, with a low score
+        let (s, confidence) = interpolator
+            .reverse_interpolate_scored(&[200, -200, 9])
+            .unwrap();
+        assert_eq!(s, 8);
+        assert_eq!(confidence, 0.0);
+
+        let flat = LinearInterpolator::new(0.0..=1.0, &[[5u8], [5]]);
+        assert_eq!(flat.reverse_interpolate_scored(&[5]), None);
+    }
+
+    #[test]
+    fn test_interpolate_into() {
+        let interpolator = LinearInterpolator::new_with_modes(
+            (100u8, 0),
+            &[[0u16, 9], [1000, 4], [500, 0]],
+            [ChannelMode::Continuous, ChannelMode::Step],
+        )
+        .with_output_clamp([100, 0], [900, 9]);
+
+        let mut out = [u16::MAX; 2];
+        for s in [0, 1, 25, 49, 50, 51, 75, 99, 100, 101, 255] {
+            interpolator.interpolate_into(s, &mut out);
+            assert_eq!(out, interpolator.interpolate(s));
+        }
+
+        let saturating = interpolator.with_out_of_range(OutOfRange::Saturate);
+        saturating.interpolate_into(200, &mut out);
+        assert_eq!(out, saturating.interpolate(200));
+    }
+
+    #[test]
+    fn test_sample_averaged() {
+        let interpolator = LinearInterpolator::new((100.0, 0.0), &[[0u8, 200], [200, 0]]);
+
+        // Without oversampling, each span is sampled at its center
+        assert_eq!(
+            interpolator.sample_averaged(4, 1),
+            vec![[25, 175], [75, 125], [125, 75], [175, 25]]
+        );
+
+        // A linear gradient averages to the same values, up to rounding
+        let floats = interpolator.cast_values::<f64>().unwrap();
+        let averaged = floats.sample_averaged(4, 5);
+        for (averaged, point) in averaged.iter().zip(floats.sample_averaged(4, 0)) {
+            assert!(averaged
+                .iter()
+                .zip(point)
+                .all(|(a, p)| (a - p).abs() < 1e-9));
+        }
+
+        let stepped =
+            LinearInterpolator::from_soa(&[0.0, 0.5, 0.5, 1.0], &[[0.0], [0.0], [1.0], [1.0]])
+                .unwrap();
+        assert_eq!(stepped.sample_averaged(3, 4), vec![[0.0], [0.5], [1.0]]);
+        assert!(stepped.sample_averaged(0, 4).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_normalized_stops() {
+        let interpolator =
+            LinearInterpolator::from_soa(&[10u8, 15, 30, 50], &[[0u8], [1], [2], [3]]).unwrap();
+        assert_eq!(interpolator.normalized_stops(), vec![0.0, 0.125, 0.5, 1.0]);
+
+        let reversed = LinearInterpolator::new((8i32, -8), &[[0u8], [1], [2], [3], [4]]);
+        assert_eq!(reversed.normalized_stops(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        // A single value set is held across the range, as a stop at each end
+        let single = LinearInterpolator::new(0.0..=1.0, &[[7u8]]);
+        assert_eq!(single.normalized_stops(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_value_operators() {
+        let interpolator = LinearInterpolator::new(0.0..=2.0, &[[10i16, 0], [20, -30000], [30, 0]])
+            .with_output_clamp([0, -100], [50, 100]);
+
+        let scaled = interpolator.clone() * 2.0;
+        assert_eq!(scaled, interpolator.scale_output(2.0));
+        assert_eq!(scaled.value_sets(), vec![[20, 0], [40, -i16::MAX], [60, 0]]);
+        assert_eq!(scaled.output_clamp(), interpolator.output_clamp());
+
+        let offset = interpolator.clone() + [5, -10000];
+        assert_eq!(offset, interpolator.offset_output([5, -10000]));
+        assert_eq!(
+            offset.value_sets(),
+            vec![[15, -10000], [25, -i16::MAX], [35, -10000]]
         );
+    }
 
+    #[test]
+    fn test_interpolate_log() {
+        fn close<const N: usize>(actual: Option<[f64; N]>, expected: [f64; N]) -> bool {
+            let actual = actual.unwrap();
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| (a - e).abs() < 1e-9)
+        }
+
+        // Evenly spaced stops get one decade each, even on a reversed integer range
         let interpolator = LinearInterpolator::new(
-            100.0..=0.0,
-            &[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]],
+            (10000u32, 10),
+            &[[300.0, 9.0], [200.0, 9.0], [100.0, 9.0], [0.0, 9.0]],
         );
-        assert_eq!(interpolator.buckets().len(), 3);
+        assert_eq!(interpolator.interpolate_log(10000), Some([300.0, 9.0]));
+        assert_eq!(interpolator.interpolate_log(10), Some([0.0, 9.0]));
+        assert!(close(interpolator.interpolate_log(1000), [200.0, 9.0]));
+        assert!(close(interpolator.interpolate_log(100), [100.0, 9.0]));
+        assert!((interpolator.interpolate_log(3162).unwrap()[0] - 250.0).abs() < 0.01);
+
+        // Inputs outside of the range, including zero, are clamped to it
+        assert_eq!(interpolator.interpolate_log(0), Some([0.0, 9.0]));
+        assert_eq!(interpolator.interpolate_log(u32::MAX), Some([300.0, 9.0]));
+
+        // The output clamp and channel modes still apply
+        let clamped = interpolator
+            .clone()
+            .with_output_clamp([0.0, 0.0], [250.0, 9.0]);
+        assert_eq!(clamped.interpolate_log(10000), Some([250.0, 9.0]));
+        let stepped = LinearInterpolator::new_with_modes(
+            1.0..=100.0,
+            &[[0.0, 0.0], [10.0, 10.0]],
+            [ChannelMode::Continuous, ChannelMode::Step],
+        );
+        assert!(close(
+            stepped.interpolate_log(5.0),
+            [3.494_850_021_680_094, 0.0]
+        ));
+        assert!(close(
+            stepped.interpolate_log(20.0),
+            [6.505_149_978_319_906, 10.0]
+        ));
+
+        // Saturating extrapolates along the logarithmic axis, but zero still moves to the lowest value
+        let saturating = interpolator.with_out_of_range(OutOfRange::Saturate);
+        assert!(close(saturating.interpolate_log(100_000), [400.0, 9.0]));
+        assert!(close(saturating.interpolate_log(0), [0.0, 9.0]));
+
+        let signed = LinearInterpolator::new(-1.0..=1.0, &[[0u8], [1]]);
+        assert_eq!(signed.interpolate_log(0.5), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "outside of the interpolator's range")]
+    fn test_interpolate_log_panic() {
+        let interpolator = LinearInterpolator::new(1.0..=100.0, &[[0.0], [1.0]])
+            .with_out_of_range(OutOfRange::Panic);
+        assert!(interpolator.interpolate_log(50.0).is_some());
+        let _ = interpolator.interpolate_log(0.0);
+    }
+
+    #[test]
+    fn test_invert_channel() {
+        let interpolator =
+            LinearInterpolator::new((200u8, 0), &[[0u16, 90, 1], [100, 60, 1], [400, 0, 2]]);
+
+        let inverse = interpolator.invert_channel(0).unwrap();
+        assert_eq!(inverse.interpolate(0), [200]);
+        assert_eq!(inverse.interpolate(50), [150]);
+        assert_eq!(inverse.interpolate(250), [50]);
+        for s in [200, 150, 100, 50, 0] {
+            assert_eq!(inverse.interpolate(interpolator.interpolate(s)[0]), [s]);
+        }
+
+        // Decreasing channels give a reversed range
+        let inverse = interpolator.invert_channel(1).unwrap();
+        assert!(inverse.is_reversed());
+        assert_eq!(inverse.interpolate(30), [50]);
+
+        // Flat spans become a jump
+        let inverse = interpolator.invert_channel(2).unwrap();
+        assert_eq!(inverse.interpolate(2), [0]);
+
+        assert!(interpolator.invert_channel(3).is_none());
+        let bumpy = LinearInterpolator::new(0.0..=1.0, &[[0.0], [1.0], [0.5]]);
+        assert!(bumpy.invert_channel(0).is_none());
+    }
+
+    #[test]
+    fn test_new_signed_reversed() {
+        let value_sets = [[0u8], [1], [2], [3], [4]];
+        for (range, expected) in [
+            ((10i8, -10), [10, 5, 0, -5, -10]),
+            ((100, -100), [100, 50, 0, -50, -100]),
+            ((-120, 120), [-120, -60, 0, 60, 120]),
+        ] {
+            let interpolator = LinearInterpolator::new(range, &value_sets);
+            assert_eq!(interpolator.validate(), Ok(()));
+            let stops: Vec<_> = interpolator.stops_iter().map(|(s, _)| s).collect();
+            assert_eq!(stops, expected);
+            for (s, values) in expected.into_iter().zip(value_sets) {
+                assert_eq!(interpolator.interpolate(s), values);
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_interpolate() {
+        let interpolator = LinearInterpolator::new(0.0..=8.0, &[[0.0, 8.0], [8.0, 0.0]]);
+        assert_eq!(interpolator.try_interpolate(2.0), Ok([2.0, 6.0]));
+        assert_eq!(interpolator.try_interpolate(8.0), Ok([8.0, 0.0]));
         assert_eq!(
-            interpolator.buckets()[0],
-            InterpolationBucket::new(100.0..=66.66666666666666, [0.0, 0.0], [1.0, 1.0])
+            interpolator.try_interpolate(-1.0),
+            Err(OutOfDomain {
+                value: -1.0,
+                clamped_to: 0.0
+            })
+        );
+        assert_eq!(
+            interpolator.try_interpolate(9.0),
+            Err(OutOfDomain {
+                value: 9.0,
+                clamped_to: 8.0
+            })
         );
 
-        let empty = LinearInterpolator::<0, f64, f64>::new(0.0..=0.0, &[]);
-        assert_eq!(empty.interpolate(0.0), []);
+        // The error is reported regardless of the out-of-range policy
+        let interpolator = interpolator.with_out_of_range(OutOfRange::Panic);
+        assert!(interpolator.try_interpolate(100.0).is_err());
+
+        let interpolator = LinearInterpolator::new((10u8, 2), &[[0u8], [8]]);
+        assert_eq!(interpolator.try_interpolate(6), Ok([4]));
+        assert_eq!(
+            interpolator.try_interpolate(1),
+            Err(OutOfDomain {
+                value: 1,
+                clamped_to: 2
+            })
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_fold_samples() {
+        let interpolator = LinearInterpolator::new(8.0..=0.0, &[[0.0, 8.0], [8.0, 0.0]]);
+        let folded = interpolator.fold_samples(5, Vec::new(), |mut acc, values| {
+            acc.push(values);
+            acc
+        });
+        let tabulated: Vec<_> = interpolator
+            .tabulate(5)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(folded, tabulated);
+
+        assert_eq!(interpolator.fold_samples(0, 7, |n, _| n + 1), 7);
+        assert_eq!(
+            interpolator.fold_samples(3, 0.0, |sum, [a, b]| sum + a + b),
+            24.0
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_interpolate_signed_wide_bucket() {
+        let interpolator = LinearInterpolator::new(-100i8..=100, &[[0.0], [200.0]]);
+        assert_eq!(interpolator.interpolate(-100), [0.0]);
+        assert_eq!(interpolator.interpolate(0), [100.0]);
+        assert_eq!(interpolator.interpolate(27), [127.0]);
+        assert_eq!(interpolator.interpolate(100), [200.0]);
+    }
+
+    #[test]
+    fn test_interpolate_wrapping_channels_out_of_range() {
+        let interpolator = LinearInterpolator::new(0.0..=1.0, &[[200u8, 0], [50, 100]])
+            .with_out_of_range(OutOfRange::Saturate);
+        assert_eq!(
+            interpolator.interpolate_wrapping_channels(1.5, &[true, false]),
+            [50, 150]
+        );
+        assert_eq!(
+            interpolator.interpolate_wrapping_channels(-0.5, &[true, false]),
+            [200, 0]
+        );
+    }
+
+    #[test]
+    fn test_new_with_modes() {
+        let interpolator = LinearInterpolator::new_with_modes(
+            0.0..=100.0,
+            &[[0, 0], [100, 1], [0, 2]],
+            [ChannelMode::Continuous, ChannelMode::Step],
+        );
+
+        assert_eq!(interpolator.interpolate(20.0), [40, 0]);
+        assert_eq!(interpolator.interpolate(25.0), [50, 1]);
+        assert_eq!(interpolator.interpolate(70.0), [60, 1]);
+        assert_eq!(interpolator.interpolate(80.0), [40, 2]);
+
+        // Modes carry over to derived interpolators
+        let resampled = interpolator.resample(5).unwrap();
+        assert_eq!(resampled.channel_modes(), interpolator.channel_modes());
+    }
+
+    #[test]
+    fn test_value_sets() {
+        let value_sets = [[0u8, 5], [10, 15], [20, 25], [30, 35]];
+        let interpolator = LinearInterpolator::new(100.0..=0.0, &value_sets);
+        assert_eq!(interpolator.value_sets(), value_sets);
+
+        let single = LinearInterpolator::new(0.0..=1.0, &[[1u8]]);
+        assert_eq!(single.value_sets(), [[1], [1]]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_first_last_value() {
+        let interpolator =
+            LinearInterpolator::new(1.0..=0.0, &[[0.1, 0.2], [0.3, 0.4], [0.5, 0.6]]);
+        assert_eq!(interpolator.first_value(), [0.1, 0.2]);
+        assert_eq!(interpolator.last_value(), [0.5, 0.6]);
+    }
+
+    #[test]
+    fn test_from_soa() {
+        let interpolator =
+            LinearInterpolator::from_soa(&[10, 5, 0], &[[0u8], [100], [200]]).unwrap();
+        assert!(interpolator.is_reversed());
+        assert_eq!(interpolator.buckets().len(), 2);
+        assert_eq!(interpolator.interpolate(8), [40]);
+        assert_eq!(interpolator.interpolate(0), [200]);
+
+        let single = LinearInterpolator::from_soa(&[3], &[[7u8]]).unwrap();
+        assert_eq!(single.value_sets(), [[7], [7]]);
+        assert_eq!(single.interpolate(3), [7]);
+
+        assert!(LinearInterpolator::from_soa(&[0, 1], &[[0u8]]).is_none());
+        assert!(LinearInterpolator::from_soa(&[0, 2, 1], &[[0u8], [1], [2]]).is_none());
+        assert!(LinearInterpolator::<1, u8, u8>::from_soa(&[], &[]).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_interpolate_const() {
+        let interpolator = LinearInterpolator::new_with_modes(
+            100.0f32..=0.0,
+            &[[0.0, 0.0], [10.0, 1.0], [-3.0, 2.0], [7.5, 3.0]],
+            [ChannelMode::Continuous, ChannelMode::Step],
+        )
+        .with_output_clamp([0.0, 0.0], [8.0, 3.0]);
+
+        for s in [-10.0, 0.0, 12.5, 33.3, 50.0, 66.7, 80.0, 100.0, 110.0] {
+            assert_eq!(
+                interpolator.interpolate_const(s),
+                interpolator.interpolate(s)
+            );
+        }
+
+        let interpolator = LinearInterpolator::new(0.0f64..=1.0, &[[0.0], [1.0], [0.5]]);
+        for s in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert_eq!(
+                interpolator.interpolate_const(s),
+                interpolator.interpolate(s)
+            );
+        }
+    }
+
+    #[test]
+    fn test_output_clamp() {
+        let interpolator = LinearInterpolator::new(0.0..=100.0, &[[-50, 0], [300, 100]])
+            .with_output_clamp([0, 0], [255, 50]);
+
+        assert_eq!(interpolator.interpolate(0.0), [0, 0]);
+        assert_eq!(interpolator.interpolate(50.0), [125, 50]);
+        assert_eq!(interpolator.interpolate(100.0), [255, 50]);
+
+        // Bounds are order-independent
+        let interpolator = interpolator.with_output_clamp([255, 50], [0, 0]);
+        assert_eq!(interpolator.interpolate(100.0), [255, 50]);
+    }
+
+    #[test]
+    fn test_new_integer_widths() {
+        fn widths(interpolator: &LinearInterpolator<1, u8, u8>) -> Vec<u8> {
+            interpolator
+                .buckets()
+                .iter()
+                .map(|b| b.range().len())
+                .collect()
+        }
+
+        let interpolator = LinearInterpolator::new((0u8, 10), &[[0u8], [1], [2], [3]]);
+        assert_eq!(widths(&interpolator), [3, 3, 4]);
+
+        let value_sets: Vec<_> = (0..8).map(|i| [i]).collect();
+        for range in [(0u8, 100), (100, 0)] {
+            let interpolator = LinearInterpolator::new(range, &value_sets);
+            let widths = widths(&interpolator);
+            assert_eq!(widths.iter().map(|w| u32::from(*w)).sum::<u32>(), 100);
+            assert!(widths.iter().max().unwrap() - widths.iter().min().unwrap() <= 1);
+        }
     }
 
     #[test]
@@ -16,6 +16,16 @@
 //! The library also provides a `no_std` feature for use in embedded systems.  
 //! **Warning: The `no_std` feature disables the `LinearInterpolator` struct which enables interpolation across >2 data sets**
 //!
+//! Easing functions can be applied within each bucket; see the `easing` module for the built-in curves,
+//! or implement the `Easing` trait for your own.
+//!
+//! The `colorspace` feature adds HSL and HSV interpolation for RGB value sets.  
+//! The `simd` feature adds SIMD accelerated interpolation for `f32` value sets.  
+//! The `css` feature adds conversion of RGB(A) interpolators to CSS `linear-gradient` strings.  
+//! The `ggr` feature adds importing of GIMP gradient (`.ggr`) files as RGBA interpolators.  
+//! The `approx` feature implements the `approx` crate's `AbsDiffEq` and `RelativeEq` traits, for use with `assert_relative_eq!`.  
+//! The `image` feature adds loading of RGB interpolators from a row of pixels in an `image` crate `RgbImage`.
+//!
 //! ## Examples
 //!
 //! The simplest possible use of the library is mapping one range to another  
@@ -75,29 +85,107 @@
 //! - `f32` `f64`
 //! - `i8` `i16` `i32` `i64` `i128` `isize`
 //! - `u8` `u16` `u32` `u64` `u128` `usize`
+//! - `NonZeroU8` `NonZeroU16` `NonZeroU32` `NonZeroU64`, where `Numeric::ZERO` is the value 1 and arithmetic saturates there
+//! - `Wrapping<u8>` `Wrapping<u16>` `Wrapping<u32>` `Wrapping<u64>` `Wrapping<usize>`, which wrap instead of saturating past the ends of a gradient
 //!
 //! For other types, you can implement the `Numeric` trait.  
 //! See `examples/custom_types.rs` for an example of how to do this.
 //!
+//! For single-field wrappers around one of the types above, the `derive` feature provides `#[derive(Numeric)]`,
+//! which delegates the whole trait to the inner field.
+//!
 #![cfg_attr(feature = "no_std", no_std)]
 #![warn(missing_docs)]
 #![warn(clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)] // Module's are not being exported so they are not being repeated
+#![allow(clippy::doc_comment_double_space_linebreaks)] // Trailing double-spaces are used for line breaks in the docs
 
 mod bucket;
-pub use bucket::InterpolationBucket;
+pub use bucket::{ChannelMode, InterpolationBucket};
 
 mod range;
 pub use range::ReversibleRange;
 
+mod error;
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+pub use error::ImportError;
+pub use error::InterpolatorError;
+#[cfg(not(feature = "no_std"))]
+pub use error::{OutOfDomain, ParseError};
+
+pub mod easing;
+pub use easing::Easing;
+
+mod interpolate;
+pub use interpolate::Interpolate;
+
 #[cfg(not(feature = "no_std"))]
 mod interpolator;
 #[cfg(not(feature = "no_std"))]
-pub use interpolator::LinearInterpolator;
+pub use interpolator::{LinearInterpolator, Monotonicity, OutOfRange};
+
+#[cfg(not(feature = "no_std"))]
+mod cached;
+#[cfg(not(feature = "no_std"))]
+pub use cached::CachedInterpolator;
+
+#[cfg(not(feature = "no_std"))]
+mod cursor;
+#[cfg(not(feature = "no_std"))]
+pub use cursor::InterpolationCursor;
+
+#[cfg(not(feature = "no_std"))]
+mod table;
+#[cfg(not(feature = "no_std"))]
+pub use table::TableInterpolator;
+
+#[cfg(not(feature = "no_std"))]
+mod grid;
+#[cfg(not(feature = "no_std"))]
+pub use grid::GridInterpolator;
+
+#[cfg(not(feature = "no_std"))]
+mod tagged;
+#[cfg(not(feature = "no_std"))]
+pub use tagged::TaggedInterpolator;
+
+#[cfg(not(feature = "no_std"))]
+mod stacked;
+#[cfg(not(feature = "no_std"))]
+pub use stacked::StackedInterpolator;
+
+#[cfg(not(feature = "no_std"))]
+mod parse;
+
+#[cfg(not(feature = "no_std"))]
+mod tuple;
+#[cfg(not(feature = "no_std"))]
+pub use tuple::{Tuple2Interpolator, Tuple3Interpolator};
 
 mod number;
 pub use number::Numeric;
 
+#[cfg(feature = "derive")]
+pub use lineic_derive::Numeric;
+
+#[cfg(feature = "colorspace")]
+mod colorspace;
+
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(all(feature = "css", not(feature = "no_std")))]
+mod css;
+
+#[cfg(all(feature = "ggr", not(feature = "no_std")))]
+mod ggr;
+
+#[cfg(feature = "approx")]
+mod approx;
+
+#[cfg(all(feature = "image", not(feature = "no_std")))]
+mod image;
+
 /// This module contains a set of same-type interpolator type aliases for common numeric types.
 pub mod interpolators {
     use crate::InterpolationBucket;
@@ -231,3 +319,30 @@ pub mod interpolators {
     #[cfg(not(feature = "no_std"))]
     pub type USizeLinearInterpolator<'a, const N: usize> = LinearInterpolator<'a, N, usize, usize>;
 }
+
+/// Convenience re-exports of the types, traits and macros used in typical usage.
+///
+/// ```rust
+/// use lineic::prelude::*;
+///
+/// let interpolator = F32LinearInterpolator::new(0.0..=10.0, &[[0.0], [10.0]]);
+/// assert_eq!(interpolator.interpolate(5.0), [5.0]);
+/// ```
+pub mod prelude {
+    pub use crate::{
+        ChannelMode, Easing, Interpolate, InterpolationBucket, InterpolatorError, Numeric,
+        ReversibleRange,
+    };
+
+    #[cfg(not(feature = "no_std"))]
+    pub use crate::{
+        static_interpolator, CachedInterpolator, GridInterpolator, LinearInterpolator, OutOfDomain,
+        OutOfRange, ParseError, StackedInterpolator, TableInterpolator, TaggedInterpolator,
+        Tuple2Interpolator, Tuple3Interpolator,
+    };
+
+    pub use crate::interpolators::{F32InterpolationBucket, F64InterpolationBucket};
+
+    #[cfg(not(feature = "no_std"))]
+    pub use crate::interpolators::{F32LinearInterpolator, F64LinearInterpolator};
+}
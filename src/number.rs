@@ -15,12 +15,27 @@ use std::fmt::{Debug, Display};
 #[cfg(feature = "no_std")]
 use core::fmt::{Debug, Display};
 
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, Wrapping};
+
 /// Represents a numeric type that can be interpolated across
 /// By default, implemented for:
 /// - `f32` `f64`
 /// - `i8` `i16` `i32` `i64` `i128` `isize`
 /// - `u8` `u16` `u32` `u64` `u128` `usize`
-pub trait Numeric: Copy + PartialOrd + Debug + Display {
+/// - `NonZeroU8` `NonZeroU16` `NonZeroU32` `NonZeroU64`
+/// - `Wrapping<u8>` `Wrapping<u16>` `Wrapping<u32>` `Wrapping<u64>` `Wrapping<usize>`
+///
+/// Only `PartialOrd` is required, so that floats can be used, but all comparisons are expected to be
+/// consistent with a total order for any values actually passed to the interpolators - types that are `Ord` are always fine.
+///
+/// The checked operations must return `None` rather than panicking or wrapping on overflow;
+/// the default implementations of [`Numeric::clamp`] and [`Numeric::abs_diff`] rely on this to stay panic-free.  
+/// The one exception is `Wrapping`, which opts in to wrapping arithmetic; see its implementation for details.
+///
+/// The constants must be usable in const contexts, so types need a fixed, const-constructible `MAX`.  
+/// This rules out arbitrary-precision types such as `num_bigint::BigInt`, which have no maximum;  
+/// wrap a fixed-width integer (e.g. `i128`) instead, saturating at its bounds.
+pub trait Numeric: Clone + PartialOrd + Debug + Display {
     /// The maximum value for this type
     const MAX: Self;
 
@@ -36,16 +51,52 @@ pub trait Numeric: Copy + PartialOrd + Debug + Display {
 
     /// Clamp this number between a minimum and maximum value.  
     /// Differs from `std::cmp::Ord::clamp` in that it must handle cases where `min > max`
+    ///
+    /// The default implementation only uses comparisons, and never panics
     #[must_use]
-    fn clamp(self, min: Self, max: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self {
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
 
     /// Get the distance between two numbers, always as a positive value
+    ///
+    /// The default implementation saturates to `MAX` if the distance cannot be represented,
+    /// such as between the extremes of a signed type
     #[must_use]
     fn abs_diff(self, other: Self) -> Self {
-        if self > other {
-            self.checked_sub(other).unwrap_or(Self::ZERO)
+        let (hi, lo) = if self > other {
+            (self, other)
         } else {
-            other.checked_sub(self).unwrap_or(Self::ZERO)
+            (other, self)
+        };
+        hi.checked_sub(lo).unwrap_or(Self::MAX)
+    }
+
+    /// Interpolate between `lo` and `hi` by `t`, where `0.0` is `lo` and `1.0` is `hi`.  
+    /// This is the primitive used by the interpolators for every channel; override it for more precise types.
+    ///
+    /// `t` may be outside of `0.0..=1.0`, such as for overshooting easings, to extrapolate past `lo` or `hi`.
+    ///
+    /// The default implementation scales the distance between the values by `t`, using [`Numeric::scale`],
+    /// and saturates at `MAX` or the lowest value (`ZERO`, or `-MAX` for signed types) if the result cannot be represented
+    #[must_use]
+    fn lerp(lo: Self, hi: Self, t: f64) -> Self {
+        let descending = (lo > hi) != (t < 0.0);
+        let diff = lo.clone().abs_diff(hi);
+        let lowest = || Self::ZERO.checked_sub(Self::MAX).unwrap_or(Self::ZERO);
+
+        match diff.scale(t.abs()) {
+            Some(adj) if descending => lo.checked_sub(adj).unwrap_or_else(lowest),
+            Some(adj) => lo.checked_add(adj).unwrap_or(Self::MAX),
+            None if descending => lowest(),
+            None => Self::MAX,
         }
     }
 
@@ -84,6 +135,94 @@ pub trait Numeric: Copy + PartialOrd + Debug + Display {
     fn from_f64(value: f64) -> Option<Self>;
 }
 
+/// Check if two numbers are approximately equal, compared as f64.  
+/// They are equal if they differ by at most `epsilon`, or by at most `max_relative` times the larger of their magnitudes
+///
+/// This mirrors the semantics of the `approx` crate's `RelativeEq`
+#[allow(clippy::float_cmp)] // Exact equality is checked first, so that infinities compare equal
+pub(crate) fn relative_eq<T: Numeric>(a: &T, b: &T, epsilon: f64, max_relative: f64) -> bool {
+    let (a, b) = (a.clone().into_f64(), b.clone().into_f64());
+    if a == b {
+        return true;
+    }
+
+    let diff = f64::abs(a - b);
+    diff <= epsilon || diff <= f64::abs(a).max(f64::abs(b)) * max_relative
+}
+
+/// The value an overflowing operation on type T saturates to; T's maximum if `positive`, otherwise its lowest value
+pub(crate) fn saturate<T: Numeric>(positive: bool) -> T {
+    if positive {
+        T::MAX
+    } else {
+        // For signed types this is `-MAX`, which is within one of the true minimum
+        T::ZERO.checked_sub(T::MAX).unwrap_or(T::ZERO)
+    }
+}
+
+/// Multiply a value by `factor` in f64, saturating if the result cannot be represented by type T
+pub(crate) fn saturating_scale<T: Numeric>(value: T, factor: f64) -> T {
+    let scaled = value.into_f64() * factor;
+    T::from_f64(scaled).unwrap_or_else(|| saturate(scaled > 0.0))
+}
+
+/// Add `delta` to a value, saturating if the result overflows type T
+pub(crate) fn saturating_offset<T: Numeric>(value: T, delta: T) -> T {
+    let positive = delta > T::ZERO;
+    value
+        .checked_add(delta)
+        .unwrap_or_else(|| saturate(positive))
+}
+
+/// Largest integer magnitude that converts to f64 exactly; beyond it, integers are scaled with [`scale_magnitude`]
+const F64_EXACT_LIMIT: u128 = 1 << f64::MANTISSA_DIGITS;
+
+/// Multiply an integer magnitude by the absolute value of `factor`, truncating towards zero.  
+/// The factor is split into its exact mantissa and exponent, so no precision is lost to an f64 round-trip.
+///
+/// Returns None if `factor` is not finite, or if the result does not fit in a u128
+fn scale_magnitude(magnitude: u128, factor: f64) -> Option<u128> {
+    if !factor.is_finite() {
+        return None;
+    }
+
+    // |factor| == mantissa * 2^exponent
+    let bits = factor.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    // magnitude * mantissa == high * 2^64 + low, with neither half overflowing
+    let high = (magnitude >> 64) * u128::from(mantissa);
+    let low = (magnitude & u128::from(u64::MAX)) * u128::from(mantissa);
+
+    if exponent >= 0 {
+        let shift = exponent.unsigned_abs();
+        let product = high.checked_mul(1 << 64)?.checked_add(low)?;
+        if product == 0 {
+            Some(0)
+        } else if product.leading_zeros() < shift {
+            None
+        } else {
+            Some(product << shift)
+        }
+    } else {
+        let shift = exponent.unsigned_abs();
+        if shift >= 64 {
+            // floor((high * 2^64 + low) / 2^shift) == floor((high + floor(low / 2^64)) / 2^(shift - 64))
+            let carried = high + (low >> 64);
+            Some(carried.checked_shr(shift - 64).unwrap_or(0))
+        } else {
+            high.checked_mul(1 << (64 - shift))?
+                .checked_add(low >> shift)
+        }
+    }
+}
+
 macro_rules! auto_impl_u {
     ($t:ty) => {
         impl Numeric for $t {
@@ -95,13 +234,17 @@ macro_rules! auto_impl_u {
                 self
             }
 
-            fn clamp(self, min: Self, max: Self) -> Self {
-                let (min, max) = (min.min(max), min.max(max));
-                match self {
-                    _ if self < min => min,
-                    _ if self > max => max,
-                    _ => self,
+            fn scale(self, factor: impl Numeric) -> Option<Self> {
+                let factor = factor.into_f64();
+                if (self as u128) <= F64_EXACT_LIMIT {
+                    return Self::from_f64(self as f64 * factor);
+                }
+
+                let magnitude = scale_magnitude(self as u128, factor)?;
+                if magnitude != 0 && factor.is_sign_negative() {
+                    return None;
                 }
+                Self::try_from(magnitude).ok()
             }
 
             fn checked_sub(self, other: Self) -> Option<Self> {
@@ -150,13 +293,19 @@ macro_rules! auto_impl_i {
                 <$t>::abs(self)
             }
 
-            fn clamp(self, min: Self, max: Self) -> Self {
-                let (min, max) = (min.min(max), min.max(max));
-                match self {
-                    _ if self < min => min,
-                    _ if self > max => max,
-                    _ => self,
+            fn scale(self, factor: impl Numeric) -> Option<Self> {
+                let factor = factor.into_f64();
+                if (self.unsigned_abs() as u128) <= F64_EXACT_LIMIT {
+                    return Self::from_f64(self as f64 * factor);
                 }
+
+                let magnitude = scale_magnitude(self.unsigned_abs() as u128, factor)?;
+                let value = if (self < 0) == factor.is_sign_negative() {
+                    i128::try_from(magnitude).ok()?
+                } else {
+                    0i128.checked_sub_unsigned(magnitude)?
+                };
+                Self::try_from(value).ok()
             }
 
             fn checked_sub(self, other: Self) -> Option<Self> {
@@ -194,6 +343,113 @@ macro_rules! auto_impl_i {
     };
 }
 
+/// Non-zero integers hold their real values, so conversions to and from f64 are unchanged.  
+/// Zero cannot be represented, so `ZERO` is the smallest valid value, 1, the same as `ONE`,
+/// and arithmetic saturates at 1 instead of reaching 0. Only overflow past `MAX` returns `None`.
+///
+/// Interpolation is done on the underlying integers, so it stays exact; see [`Numeric::lerp`].
+macro_rules! auto_impl_nonzero {
+    ($t:ty, $inner:ty) => {
+        impl Numeric for $t {
+            const MAX: Self = <$t>::MAX;
+            const ZERO: Self = <$t>::MIN;
+            const ONE: Self = <$t>::MIN;
+
+            fn abs(self) -> Self {
+                self
+            }
+
+            fn lerp(lo: Self, hi: Self, t: f64) -> Self {
+                <$t>::new(<$inner as Numeric>::lerp(lo.get(), hi.get(), t)).unwrap_or(<$t>::MIN)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(<$t>::new(self.get().saturating_sub(other.get())).unwrap_or(<$t>::MIN))
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other.get())
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                Some(<$t>::new(self.get() / other.get()).unwrap_or(<$t>::MIN))
+            }
+
+            fn from_usize(value: usize) -> Option<Self> {
+                <$t>::new(<$inner>::try_from(value).ok()?)
+            }
+
+            fn into_f64(self) -> f64 {
+                self.get() as f64
+            }
+
+            fn from_f64(value: f64) -> Option<Self> {
+                Some(<$t>::new(<$inner as Numeric>::from_f64(value)?).unwrap_or(<$t>::MIN))
+            }
+        }
+    };
+}
+
+/// Wrapping integers deliberately break the rule that checked operations fail on overflow:  
+/// they always return `Some`, wrapping around the type's bounds, and `from_f64` wraps the same way.  
+/// Only division by zero returns `None`, as does `from_usize` for counts that do not fit, since those are used for stop counts and steps.
+///
+/// Interpolating between 2 values never overflows, so results match the plain integer type within a bucket.  
+/// The difference is past the ends, such as with overshooting easings or [`crate::OutOfRange::Saturate`]:
+/// where a plain `u8` gradient saturates at 0 or 255, a `Wrapping<u8>` one wraps around, as a phase accumulator would.
+macro_rules! auto_impl_wrapping {
+    ($t:ty) => {
+        impl Numeric for Wrapping<$t> {
+            const MAX: Self = Wrapping(<$t>::MAX);
+            const ZERO: Self = Wrapping(0);
+            const ONE: Self = Wrapping(1);
+
+            fn abs(self) -> Self {
+                self
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(self - other)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                Some(self + other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                Some(self * other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                self.0.checked_div(other.0).map(Wrapping)
+            }
+
+            fn from_usize(value: usize) -> Option<Self> {
+                <$t>::try_from(value).ok().map(Wrapping)
+            }
+
+            fn into_f64(self) -> f64 {
+                self.0 as f64
+            }
+
+            fn from_f64(value: f64) -> Option<Self> {
+                if !value.is_finite() {
+                    return None;
+                }
+
+                // The remainder is exact, and fits in an i128 for every type up to 64 bits
+                let modulus = 1i128 << <$t>::BITS;
+                let remainder = (value % modulus as f64) as i128;
+                Some(Wrapping(remainder.rem_euclid(modulus) as $t))
+            }
+        }
+    };
+}
+
 impl Numeric for f64 {
     const MAX: Self = f64::MAX;
     const ZERO: Self = 0.0;
@@ -302,6 +558,15 @@ auto_impl_u!(u32);
 auto_impl_u!(u64);
 auto_impl_u!(u128);
 auto_impl_u!(usize);
+auto_impl_nonzero!(NonZeroU8, u8);
+auto_impl_nonzero!(NonZeroU16, u16);
+auto_impl_nonzero!(NonZeroU32, u32);
+auto_impl_nonzero!(NonZeroU64, u64);
+auto_impl_wrapping!(u8);
+auto_impl_wrapping!(u16);
+auto_impl_wrapping!(u32);
+auto_impl_wrapping!(u64);
+auto_impl_wrapping!(usize);
 
 #[cfg(test)]
 mod test {
@@ -352,4 +617,228 @@ mod test {
         assert_eq!(Numeric::scale(1u8, 2), Some(2));
         assert_eq!(Numeric::scale(2u8, 0.5), Some(1));
     }
+
+    /// A minimal integer-like type that is `Ord`, and relies on the default trait methods
+    /// It is deliberately not `Copy`, to make sure the library only relies on `Clone`
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Level(i8);
+    impl Display for Level {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "L{}", self.0)
+        }
+    }
+    impl Numeric for Level {
+        const MAX: Self = Level(i8::MAX);
+        const ZERO: Self = Level(0);
+        const ONE: Self = Level(1);
+
+        fn abs(self) -> Self {
+            Level(self.0.saturating_abs())
+        }
+
+        fn checked_sub(self, other: Self) -> Option<Self> {
+            self.0.checked_sub(other.0).map(Level)
+        }
+
+        fn checked_add(self, other: Self) -> Option<Self> {
+            self.0.checked_add(other.0).map(Level)
+        }
+
+        fn checked_mul(self, other: Self) -> Option<Self> {
+            self.0.checked_mul(other.0).map(Level)
+        }
+
+        fn checked_div(self, other: Self) -> Option<Self> {
+            self.0.checked_div(other.0).map(Level)
+        }
+
+        fn from_usize(value: usize) -> Option<Self> {
+            i8::try_from(value).ok().map(Level)
+        }
+
+        fn into_f64(self) -> f64 {
+            f64::from(self.0)
+        }
+
+        fn from_f64(value: f64) -> Option<Self> {
+            i8::from_f64(value).map(Level)
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_lerp() {
+        assert_eq!(Numeric::lerp(0.0f64, 10.0, 0.25), 2.5);
+        assert_eq!(Numeric::lerp(10.0f32, 0.0, 0.25), 7.5);
+        assert_eq!(Numeric::lerp(-10i8, 10, 0.5), 0);
+        assert_eq!(Numeric::lerp(200u8, 100, 0.5), 150);
+        assert_eq!(Numeric::lerp(Level(10), Level(-10), 0.75), Level(-5));
+
+        // Results are truncated towards `lo`
+        assert_eq!(Numeric::lerp(0u8, 3, 0.5), 1);
+        assert_eq!(Numeric::lerp(3u8, 0, 0.5), 2);
+
+        // Extrapolation past either end saturates
+        assert_eq!(Numeric::lerp(0u8, 100, 1.5), 150);
+        assert_eq!(Numeric::lerp(0u8, 100, -0.5), 0);
+        assert_eq!(Numeric::lerp(100u8, 0, -0.5), 150);
+        assert_eq!(Numeric::lerp(0u8, 200, 2.0), u8::MAX);
+        assert_eq!(Numeric::lerp(-10i8, 10, -0.5), -20);
+        assert_eq!(Numeric::lerp(50i8, -50, 1.8), -i8::MAX);
+        assert_eq!(Numeric::lerp(0.0f64, 10.0, -0.25), -2.5);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_nonzero() {
+        fn nz(value: u32) -> NonZeroU32 {
+            NonZeroU32::new(value).unwrap()
+        }
+
+        // Values are not offset, and zero is represented by the smallest valid value
+        assert_eq!(NonZeroU32::ZERO, nz(1));
+        assert_eq!(NonZeroU32::ONE, nz(1));
+        assert_eq!(nz(1).into_f64(), 1.0);
+        assert_eq!(nz(10).into_f64(), 10.0);
+        assert_eq!(NonZeroU32::from_f64(5.0), Some(nz(5)));
+        assert_eq!(NonZeroU32::from_usize(4), Some(nz(4)));
+        assert_eq!(NonZeroU32::from_usize(0), None);
+        assert_eq!(Numeric::abs_diff(nz(7), nz(3)), nz(4));
+
+        // Results saturate at 1 rather than reaching zero
+        assert_eq!(Numeric::abs_diff(nz(7), nz(7)), nz(1));
+        assert_eq!(Numeric::checked_sub(nz(3), nz(4)), Some(nz(1)));
+        assert_eq!(Numeric::checked_div(nz(3), nz(4)), Some(nz(1)));
+        assert_eq!(NonZeroU32::from_f64(0.0), Some(nz(1)));
+        assert_eq!(NonZeroU32::from_f64(-1.0), None);
+
+        // Overflow past `MAX` still fails
+        assert_eq!(Numeric::checked_add(nz(3), nz(4)), Some(nz(7)));
+        assert_eq!(Numeric::checked_add(NonZeroU32::MAX, NonZeroU32::ONE), None);
+        assert_eq!(Numeric::checked_mul(nz(3), nz(4)), Some(nz(12)));
+        assert_eq!(Numeric::checked_mul(NonZeroU32::MAX, nz(2)), None);
+
+        // Interpolation is exact in both directions
+        assert_eq!(Numeric::lerp(nz(1), nz(11), 0.5), nz(6));
+        assert_eq!(Numeric::lerp(nz(10), nz(20), 0.0), nz(10));
+        assert_eq!(
+            Numeric::lerp(NonZeroU8::MAX, NonZeroU8::MIN, 1.0),
+            NonZeroU8::MIN
+        );
+        assert_eq!(Numeric::lerp(nz(2), nz(1), 5.0), nz(1));
+
+        let bucket = crate::InterpolationBucket::new((nz(1), nz(101)), [0u8], [100]);
+        assert_eq!(bucket.interpolate(nz(1)), [0]);
+        assert_eq!(bucket.interpolate(nz(51)), [50]);
+        assert_eq!(bucket.interpolate(nz(101)), [100]);
+
+        let bucket =
+            crate::InterpolationBucket::new((0u8, 10), [NonZeroU64::MIN], [NonZeroU64::MAX]);
+        assert_eq!(bucket.interpolate(0), [NonZeroU64::MIN]);
+        assert_eq!(bucket.interpolate(10), [NonZeroU64::MAX]);
+
+        // Conversions through f64 keep the real values
+        let bucket = crate::InterpolationBucket::new((0.0, 1.0), [nz(10)], [nz(20)]);
+        assert_eq!(bucket.interpolate(0.5), [nz(15)]);
+        assert_eq!((bucket * 2.0).values_lo(), &[nz(20)]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_wrapping() {
+        let w = Wrapping::<u8>;
+
+        // Checked operations wrap instead of failing
+        assert_eq!(Numeric::checked_add(w(250), w(10)), Some(w(4)));
+        assert_eq!(Numeric::checked_sub(w(3), w(4)), Some(w(255)));
+        assert_eq!(Numeric::checked_mul(w(16), w(17)), Some(w(16)));
+        assert_eq!(Numeric::checked_div(w(3), w(0)), None);
+        assert_eq!(Wrapping::<u8>::from_usize(255), Some(w(255)));
+        assert_eq!(Wrapping::<u8>::from_usize(300), None);
+        assert_eq!(Wrapping::<u8>::from_f64(-1.0), Some(w(255)));
+        assert_eq!(Wrapping::<u8>::from_f64(513.9), Some(w(1)));
+        assert_eq!(Wrapping::<u8>::from_f64(f64::NAN), None);
+        assert_eq!(
+            Wrapping::<u64>::from_f64(-2.0),
+            Some(Wrapping(u64::MAX - 1))
+        );
+        assert_eq!(w(200).into_f64(), 200.0);
+
+        // Distances are unaffected
+        assert_eq!(Numeric::abs_diff(w(3), w(250)), w(247));
+
+        // Within the ends, interpolation matches the plain type, and past them it wraps
+        assert_eq!(Numeric::lerp(w(0), w(200), 0.5), w(100));
+        assert_eq!(Numeric::lerp(w(250), w(255), 2.0), w(4));
+        assert_eq!(Numeric::lerp(w(5), w(0), 2.0), w(251));
+        assert_eq!(Numeric::lerp(250u8, 255, 2.0), 255);
+        assert_eq!(Numeric::lerp(5u8, 0, 2.0), 0);
+    }
+
+    #[test]
+    fn test_scale_large_integers() {
+        // Not representable as f64, so the f64 path would land on 500000000000000009942312419328
+        const HI: i128 = 1_000_000_000_000_000_000_000_000_007;
+        assert_eq!(
+            Numeric::lerp(0i128, HI, 0.5),
+            500_000_000_000_000_000_000_000_003
+        );
+        assert_eq!(
+            Numeric::lerp(HI, 0i128, 0.5),
+            500_000_000_000_000_000_000_000_004
+        );
+        assert_eq!(
+            Numeric::lerp(-HI, HI, 0.25),
+            -500_000_000_000_000_000_000_000_004
+        );
+        assert_eq!(
+            Numeric::lerp(0u128, HI as u128, 0.5),
+            500_000_000_000_000_000_000_000_003
+        );
+
+        let bucket = crate::InterpolationBucket::new((0u8, 2), [0i128], [HI]);
+        assert_eq!(bucket.interpolate(1), [500_000_000_000_000_000_000_000_003]);
+
+        assert_eq!(Numeric::scale(HI, -1.0), Some(-HI));
+        assert_eq!(Numeric::scale(HI, 2.0), Some(2 * HI));
+        assert_eq!(Numeric::scale(i128::MIN, 1.0), Some(i128::MIN));
+        assert_eq!(Numeric::scale(i128::MIN, -1.0), None);
+        assert_eq!(Numeric::scale(u128::MAX, 0.5), Some(u128::MAX / 2));
+        assert_eq!(Numeric::scale(u128::MAX, -0.5), None);
+        assert_eq!(Numeric::scale(u128::MAX, 1e-300), Some(0));
+        assert_eq!(Numeric::scale(HI, f64::NAN), None);
+        assert_eq!(Numeric::scale(u64::MAX, 0.5), Some(u64::MAX / 2));
+    }
+
+    #[test]
+    fn test_default_methods() {
+        // clamp handles reversed bounds
+        assert_eq!(Numeric::clamp(Level(5), Level(0), Level(3)), Level(3));
+        assert_eq!(Numeric::clamp(Level(5), Level(3), Level(0)), Level(3));
+        assert_eq!(Numeric::clamp(Level(-5), Level(3), Level(0)), Level(0));
+        assert_eq!(Numeric::clamp(Level(2), Level(3), Level(0)), Level(2));
+
+        // abs_diff is symmetric, and saturates instead of collapsing to zero on overflow
+        assert_eq!(Numeric::abs_diff(Level(-3), Level(4)), Level(7));
+        assert_eq!(Numeric::abs_diff(Level(4), Level(-3)), Level(7));
+        assert_eq!(Numeric::abs_diff(Level(-100), Level(100)), Level::MAX);
+        assert_eq!(Numeric::abs_diff(-100i8, 100), i8::MAX);
+
+        let bucket =
+            crate::InterpolationBucket::new((Level(-10), Level(10)), [Level(0)], [Level(100)]);
+        assert_eq!(bucket.interpolate(Level(0)), [Level(50)]);
+        assert_eq!(bucket.interpolate(Level(20)), [Level(100)]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_clone_only_interpolator() {
+        let value_sets = [[Level(0), Level(10)], [Level(100), Level(-10)]];
+        let interpolator = crate::LinearInterpolator::new((Level(10), Level(-10)), &value_sets);
+        assert_eq!(interpolator.interpolate(Level(0)), [Level(50), Level(0)]);
+        assert_eq!(
+            interpolator.reverse_interpolate(&[Level(50), Level(0)]),
+            Some(Level(0))
+        );
+    }
 }
@@ -0,0 +1,237 @@
+use crate::{InterpolationBucket, LinearInterpolator, Numeric, ParseError};
+use std::str::FromStr;
+
+/// A cursor over the input, tracking the line and column of the current position
+pub(crate) struct Parser<'s> {
+    input: &'s str,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+impl<'s> Parser<'s> {
+    pub(crate) fn new(input: &'s str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub(crate) fn rest(&self) -> &'s str {
+        &self.input[self.pos..]
+    }
+
+    pub(crate) fn bump(&mut self) -> Option<char> {
+        let c = self.rest().chars().next()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        while self.rest().starts_with(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    pub(crate) fn error(&self, expected: &'static str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            expected,
+        }
+    }
+
+    /// Consume `token`, after any whitespace
+    pub(crate) fn expect(&mut self, token: &str, expected: &'static str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if !self.rest().starts_with(token) {
+            return Err(self.error(expected));
+        }
+
+        for _ in token.chars() {
+            self.bump();
+        }
+        Ok(())
+    }
+
+    /// Consume a single numeric literal, after any whitespace
+    pub(crate) fn number<V: FromStr>(&mut self) -> Result<V, ParseError> {
+        self.skip_whitespace();
+        let error = self.error("a number");
+
+        let start = self.pos;
+        let mut previous = None;
+        while let Some(c) = self.rest().chars().next() {
+            let is_sign = matches!(c, '+' | '-') && matches!(previous, None | Some('e' | 'E'));
+            if !(c.is_ascii_alphanumeric() || c == '.' || is_sign) {
+                break;
+            }
+
+            previous = self.bump();
+        }
+
+        self.input[start..self.pos].parse().map_err(|_| error)
+    }
+
+    /// Consume a bracketed, comma separated list of exactly `N` numbers
+    fn values<const N: usize, V: FromStr>(&mut self) -> Result<[V; N], ParseError> {
+        self.expect("[", "`[`")?;
+        self.skip_whitespace();
+        let error = self.error("one value per channel");
+
+        let mut values = Vec::with_capacity(N);
+        loop {
+            values.push(self.number()?);
+            self.skip_whitespace();
+            if self.rest().starts_with(',') {
+                self.bump();
+                self.skip_whitespace();
+            }
+
+            if self.rest().starts_with(']') {
+                self.bump();
+                break;
+            } else if self.rest().is_empty() {
+                return Err(self.error("`]`"));
+            }
+        }
+
+        values.try_into().map_err(|_| error)
+    }
+
+    /// Consume a single `(start => end) [values_from] => [values_to]` statement
+    fn bucket<const N: usize, S: Numeric + FromStr, T: Numeric + FromStr>(
+        &mut self,
+    ) -> Result<InterpolationBucket<N, S, T>, ParseError> {
+        self.expect("(", "`(`")?;
+        let start: S = self.number()?;
+        self.expect("=>", "`=>`")?;
+        let end: S = self.number()?;
+        self.expect(")", "`)`")?;
+
+        let values_lo = self.values()?;
+        self.expect("=>", "`=>`")?;
+        let values_hi = self.values()?;
+
+        Ok(InterpolationBucket::new((start, end), values_lo, values_hi))
+    }
+}
+
+impl<const N: usize, S: Numeric + FromStr, T: Numeric + FromStr> LinearInterpolator<'_, N, S, T> {
+    /// Parse an interpolator at runtime, from the same syntax used by the [`crate::static_interpolator!`] macro.  
+    /// This allows gradients to be loaded from config files, or hot-reloaded during development.
+    ///
+    /// Values are parsed with [`FromStr`], so any type implementing it can be used, including all the built-in types.  
+    /// As with the macro, the buckets are not checked for continuity; see [`Self::validate`].
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] with the line and column of the first syntax error,
+    /// or of a value set that does not have exactly `N` values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::LinearInterpolator;
+    ///
+    /// let interpolator: LinearInterpolator<3, f32, f32> = LinearInterpolator::parse("
+    ///     (0.0 => 50.0) [0.0, 0.0, 0.0] => [1.0, 1.0, 1.0];
+    ///     (50.0 => 100.0) [1.0, 1.0, 1.0] => [2.0, 2.0, 2.0];
+    /// ").unwrap();
+    /// assert_eq!(interpolator.interpolate(75.0), [1.5, 1.5, 1.5]);
+    ///
+    /// let error = LinearInterpolator::<3, f32, f32>::parse("(0.0 => 50.0) [0.0, 0.0] => [1.0, 1.0]").unwrap_err();
+    /// assert_eq!(error.to_string(), "expected one value per channel at line 1, column 16");
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser::new(input);
+        let mut buckets = Vec::new();
+
+        loop {
+            parser.skip_whitespace();
+            if parser.rest().is_empty() && !buckets.is_empty() {
+                break;
+            }
+
+            buckets.push(parser.bucket()?);
+            parser.skip_whitespace();
+            if parser.rest().is_empty() {
+                break;
+            }
+
+            parser.expect(";", "`;`")?;
+        }
+
+        Ok(Self::from_buckets(buckets))
+    }
+}
+
+impl<const N: usize, S: Numeric + FromStr, T: Numeric + FromStr> FromStr
+    for LinearInterpolator<'_, N, S, T>
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::static_interpolator;
+
+    #[test]
+    fn test_parse() {
+        const EXPECTED: LinearInterpolator<3, f64, f64> = static_interpolator! {
+            (0.0 => 50.0) [0.0, 0.0, 0.0] => [1.0, 1.0, 1.0];
+            (50.0 => 100.0) [1.0, 1.0, 1.0] => [2.0, -2.5e-3, 2.0];
+        };
+
+        let parsed: LinearInterpolator<3, f64, f64> = "
+            (0.0 => 50.0) [0.0, 0.0, 0.0] => [1.0, 1.0, 1.0];
+            (50.0=>100.0)[1.0,1.0,1.0,]=>[2.0,-2.5e-3,2.0];
+        "
+        .parse()
+        .unwrap();
+        assert_eq!(parsed, EXPECTED);
+
+        // Integer types, reversed ranges, and a single bucket without a trailing semicolon
+        let parsed = LinearInterpolator::<2, u8, i16>::parse("(10 => 0) [0, -5] => [200, 5]");
+        assert_eq!(
+            parsed.unwrap().buckets(),
+            [InterpolationBucket::new((10, 0), [0, -5], [200, 5])]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        fn error(input: &str) -> (usize, usize, &'static str) {
+            let error = LinearInterpolator::<2, f32, u8>::parse(input).unwrap_err();
+            (error.line, error.column, error.expected)
+        }
+
+        assert_eq!(error(""), (1, 1, "`(`"));
+        assert_eq!(error("  \n  "), (2, 3, "`(`"));
+        assert_eq!(error("(0.0 -> 1.0)"), (1, 6, "`=>`"));
+        assert_eq!(error("(0.0 => x) [0, 0] => [1, 1]"), (1, 9, "a number"));
+        assert_eq!(
+            error("(0.0 => 1.0) [0, 0] => [1, 1.5]"),
+            (1, 28, "a number")
+        );
+        assert_eq!(
+            error("(0.0 => 1.0) [0, 0] => [1, 1, 1]"),
+            (1, 25, "one value per channel")
+        );
+        assert_eq!(error("(0.0 => 1.0) [0, 0] => [1, 1"), (1, 29, "`]`"));
+        assert_eq!(
+            error("(0.0 => 1.0) [0, 0] => [1, 1]\n(1.0 => 2.0) [1, 1] => [2, 2]"),
+            (2, 1, "`;`")
+        );
+    }
+}
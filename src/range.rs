@@ -1,6 +1,21 @@
-use crate::Numeric;
+use crate::{number::relative_eq, Numeric};
 
 /// An inclusive total range that can be used in reverse order
+///
+/// # Example
+/// ```rust
+/// use lineic::ReversibleRange;
+///
+/// let range = ReversibleRange::new(10, 0);
+/// assert!(range.is_reversed());
+/// assert!(range.contains(5));
+/// assert!(!range.contains(11));
+/// assert_eq!(range.len(), 10);
+///
+/// // Ranges can also be created from arrays, tuples, or `start..=end`
+/// assert_eq!(ReversibleRange::from((10, 0)), range);
+/// assert_eq!(ReversibleRange::from([10, 0]), range);
+/// ```
 #[derive(Debug, PartialEq, Clone, Hash, Eq)]
 pub struct ReversibleRange<S: Numeric> {
     /// The starting point of the range
@@ -23,6 +38,7 @@ impl<S: Numeric> ReversibleRange<S> {
 
     /// Check if the range contains the given value
     /// Returns true if value is between the start and end values
+    #[allow(clippy::needless_pass_by_value)] // Inputs are taken by value throughout the API, as they are usually Copy
     pub fn contains(&self, value: S) -> bool {
         (self.start <= value && value <= self.end) || (self.end <= value && value <= self.start)
     }
@@ -35,7 +51,7 @@ impl<S: Numeric> ReversibleRange<S> {
 
     /// Get the length of the range
     pub fn len(&self) -> S {
-        self.start.abs_diff(self.end)
+        self.start.clone().abs_diff(self.end.clone())
     }
 
     /// Check if the range is reversed
@@ -43,6 +59,219 @@ impl<S: Numeric> ReversibleRange<S> {
     pub fn is_reversed(&self) -> bool {
         self.start > self.end
     }
+
+    /// Get the point at `t` of the way from the start of the range to its end.  
+    /// `t` is expected to be in `0.0..=1.0`, where `0.0` maps to `start` and `1.0` maps to `end`.
+    ///
+    /// ```rust
+    /// use lineic::ReversibleRange;
+    ///
+    /// assert_eq!(ReversibleRange::new(0.0, 10.0).lerp(0.25), 2.5);
+    /// assert_eq!(ReversibleRange::new(10.0, 0.0).lerp(0.25), 7.5);
+    /// ```
+    pub fn lerp(&self, t: f64) -> S {
+        let len = self.len();
+        let offset = len.clone().scale(t).unwrap_or(len);
+        let start = self.start.clone();
+        let offset = if self.is_reversed() {
+            start.checked_sub(offset)
+        } else {
+            start.checked_add(offset)
+        };
+        offset.unwrap_or_else(|| self.end.clone())
+    }
+
+    /// Get how far `value` is from the start of the range to its end, as a value in `0.0..=1.0`.  
+    /// Values outside of the range are clamped to it first.  
+    /// Empty ranges have no span to measure across, so always return `0.0`.
+    ///
+    /// This is the inverse of [`Self::lerp`].
+    ///
+    /// ```rust
+    /// use lineic::ReversibleRange;
+    ///
+    /// assert_eq!(ReversibleRange::new(0.0, 10.0).inverse_lerp(2.5), 0.25);
+    /// assert_eq!(ReversibleRange::new(10.0, 0.0).inverse_lerp(2.5), 0.75);
+    /// ```
+    pub fn inverse_lerp(&self, value: S) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        // The start is matched first, since some types such as `NonZeroU32` cannot represent a distance of zero
+        let value = value.clamp(self.start.clone(), self.end.clone());
+        if value == self.start {
+            return 0.0;
+        }
+
+        // Signed ranges can span more than MAX, which `len` saturates to; those are measured in f64 instead
+        let (lo, hi) = if self.is_reversed() {
+            (self.end.clone(), self.start.clone())
+        } else {
+            (self.start.clone(), self.end.clone())
+        };
+        if hi.checked_sub(lo).is_none() {
+            let start = self.start.clone().into_f64();
+            return (value.into_f64() - start) / (self.end.clone().into_f64() - start);
+        }
+
+        let rel_value = value.abs_diff(self.start.clone());
+        rel_value.into_f64() / self.len().into_f64()
+    }
+
+    /// Iterate over the range from `start` towards `end`, advancing by `step` each time.  
+    /// Reversed ranges are stepped through in reverse, so `step` is always a positive distance.
+    ///
+    /// Iteration stops once the next position would pass `end`, so `end` is only included
+    /// if it is a whole number of steps from `start`. A zero step yields only `start`.
+    ///
+    /// ```rust
+    /// use lineic::ReversibleRange;
+    ///
+    /// let steps: Vec<_> = ReversibleRange::new(10, 0).iter_steps(5).collect();
+    /// assert_eq!(steps, vec![10, 5, 0]);
+    ///
+    /// let steps: Vec<_> = ReversibleRange::new(0.0, 1.0).iter_steps(0.4).collect();
+    /// assert_eq!(steps, vec![0.0, 0.4, 0.8]);
+    /// ```
+    pub fn iter_steps(&self, step: S) -> impl Iterator<Item = S> {
+        let range = self.clone();
+        let step = step.abs();
+
+        core::iter::successors(Some(self.start.clone()), move |s| {
+            let next = if range.is_reversed() {
+                s.clone().checked_sub(step.clone())
+            } else {
+                s.clone().checked_add(step.clone())
+            };
+            next.filter(|next| next != s && range.contains(next.clone()))
+        })
+    }
+
+    /// Split the range in two at `value`, keeping the orientation of both halves.  
+    /// Values outside of the range are clamped to it first, leaving one of the halves empty.
+    ///
+    /// ```rust
+    /// use lineic::ReversibleRange;
+    ///
+    /// let (a, b) = ReversibleRange::new(10, 0).split_at(4);
+    /// assert_eq!(a, ReversibleRange::new(10, 4));
+    /// assert_eq!(b, ReversibleRange::new(4, 0));
+    /// ```
+    pub fn split_at(&self, value: S) -> (Self, Self) {
+        let value = value.clamp(self.start.clone(), self.end.clone());
+        (
+            Self::new(self.start.clone(), value.clone()),
+            Self::new(value, self.end.clone()),
+        )
+    }
+
+    /// Divide the range into `n` contiguous sub-ranges of equal length, in order from `start` to `end`.  
+    /// Each sub-range keeps the orientation of this one.
+    ///
+    /// Integer ranges that do not divide evenly spread the remainder across the sub-ranges,
+    /// so their lengths differ by at most 1.  
+    /// Returns an empty list if `n` is zero, or too large to be represented by type S.
+    ///
+    /// ```rust
+    /// use lineic::ReversibleRange;
+    ///
+    /// let chunks = ReversibleRange::new(0.0, 1.0).chunks(4);
+    /// assert_eq!(chunks[1], ReversibleRange::new(0.25, 0.5));
+    ///
+    /// let chunks = ReversibleRange::new(9, 0).chunks(3);
+    /// assert_eq!(chunks, vec![
+    ///     ReversibleRange::new(9, 6),
+    ///     ReversibleRange::new(6, 3),
+    ///     ReversibleRange::new(3, 0),
+    /// ]);
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn chunks(&self, n: usize) -> Vec<Self> {
+        self.split(n).map(Iterator::collect).unwrap_or_default()
+    }
+
+    /// Split the range into `count` contiguous sub-ranges of equal length, in order from `start` to `end`.  
+    /// Returns None if `count` is zero, or too large to be represented by type S
+    pub(crate) fn split(&self, count: usize) -> Option<impl Iterator<Item = Self>> {
+        if count == 0 {
+            return None;
+        }
+
+        let len = self.len();
+        let divisor = S::from_usize(count)?;
+
+        // Signed ranges can span more than MAX, which `len` saturates to;
+        // those boundaries are placed in f64 instead, so that the sub-ranges still tile the range evenly
+        let (lo, hi) = if self.is_reversed() {
+            (self.end.clone(), self.start.clone())
+        } else {
+            (self.start.clone(), self.end.clone())
+        };
+        let too_long = hi.checked_sub(lo).is_none();
+        let (start_f64, end_f64) = (self.start.clone().into_f64(), self.end.clone().into_f64());
+        let step_by = len.clone().checked_div(divisor.clone())?;
+
+        // Integer division truncates, so the remainder is spread across the sub-ranges
+        // one unit at a time (Bresenham-style), keeping their lengths within 1 of each other
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let remainder = step_by
+            .clone()
+            .checked_mul(divisor)
+            .and_then(|used| len.checked_sub(used))
+            .map_or(0, |r| r.into_f64() as usize);
+        let mut error = 0;
+
+        let range = self.clone();
+        let mut start = self.start.clone();
+        Some((0..count).map(move |i| {
+            error += remainder;
+            let step = if error >= count {
+                error -= count;
+                step_by
+                    .clone()
+                    .checked_add(S::ONE)
+                    .unwrap_or(step_by.clone())
+            } else {
+                step_by.clone()
+            };
+
+            // The last sub-range always finishes at the end of the range, as does any step that would overflow past it
+            let end = if i == count - 1 {
+                None
+            } else if too_long {
+                let t = (i + 1).into_f64() / count.into_f64();
+                S::from_f64(start_f64 + (end_f64 - start_f64) * t)
+            } else if range.is_reversed() {
+                start.clone().checked_sub(step)
+            } else {
+                start.clone().checked_add(step)
+            }
+            .unwrap_or_else(|| range.end.clone());
+            Self::new(core::mem::replace(&mut start, end.clone()), end)
+        }))
+    }
+
+    /// Check if both ends of this range are within `epsilon` of the other range's.  
+    /// Values are compared as f64; see [`Self::relative_eq`] for a tolerance that scales with magnitude.
+    ///
+    /// ```rust
+    /// use lineic::ReversibleRange;
+    ///
+    /// let range = ReversibleRange::new(0.0, 0.1 + 0.2);
+    /// assert_ne!(range, ReversibleRange::new(0.0, 0.3));
+    /// assert!(range.abs_diff_eq(&ReversibleRange::new(0.0, 0.3), 1e-9));
+    /// ```
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.relative_eq(other, epsilon, 0.0)
+    }
+
+    /// Check if both ends of this range are approximately equal to the other range's.  
+    /// Values are equal if they differ by at most `epsilon`, or by at most `max_relative` times the larger of their magnitudes.
+    pub fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        relative_eq(&self.start, &other.start, epsilon, max_relative)
+            && relative_eq(&self.end, &other.end, epsilon, max_relative)
+    }
 }
 
 impl<S> From<[S; 2]> for ReversibleRange<S>
@@ -50,10 +279,8 @@ where
     S: Numeric,
 {
     fn from(range: [S; 2]) -> Self {
-        Self {
-            start: range[0],
-            end: range[1],
-        }
+        let [start, end] = range;
+        Self { start, end }
     }
 }
 
@@ -69,12 +296,197 @@ where
     }
 }
 
+/// Converts `start..=end` into a range, keeping its order.
+///
+/// Unlike the standard library, which considers `10.0..=0.0` empty, this crate treats it as a valid reversed range;  
+/// the resulting range contains every value between 0 and 10, and runs from 10 to 0.
+///
+/// ```rust
+/// use lineic::ReversibleRange;
+///
+/// assert!(!(10.0..=0.0).contains(&5.0));
+///
+/// let range = ReversibleRange::from(10.0..=0.0);
+/// assert!(range.is_reversed());
+/// assert!(range.contains(5.0));
+/// ```
 #[cfg(not(feature = "no_std"))]
 impl<S: Numeric> From<std::ops::RangeInclusive<S>> for ReversibleRange<S> {
     fn from(range: std::ops::RangeInclusive<S>) -> Self {
-        Self {
-            start: *range.start(),
-            end: *range.end(),
+        let (start, end) = range.into_inner();
+        Self { start, end }
+    }
+}
+
+/// Converts a borrowed `start..=end` into a range, cloning its bounds and keeping its order.  
+/// See the owned conversion for how reversed ranges are treated.
+///
+/// ```rust
+/// use lineic::ReversibleRange;
+///
+/// let bounds = 10..=0;
+/// assert_eq!(ReversibleRange::from(&bounds), ReversibleRange::new(10, 0));
+/// assert!(bounds.is_empty()); // Still usable
+/// ```
+#[cfg(not(feature = "no_std"))]
+impl<S: Numeric> From<&std::ops::RangeInclusive<S>> for ReversibleRange<S> {
+    fn from(range: &std::ops::RangeInclusive<S>) -> Self {
+        Self::new(range.start().clone(), range.end().clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_lerp() {
+        let range = ReversibleRange::new(0u8, 200);
+        assert_eq!(range.lerp(0.0), 0);
+        assert_eq!(range.lerp(0.5), 100);
+        assert_eq!(range.lerp(1.0), 200);
+        assert_eq!(range.inverse_lerp(50), 0.25);
+        assert_eq!(range.inverse_lerp(255), 1.0);
+
+        let range = ReversibleRange::new(200u8, 0);
+        assert_eq!(range.lerp(0.0), 200);
+        assert_eq!(range.lerp(0.25), 150);
+        assert_eq!(range.lerp(1.0), 0);
+        assert_eq!(range.inverse_lerp(50), 0.75);
+        assert_eq!(range.inverse_lerp(255), 0.0);
+
+        let range = ReversibleRange::new(5.0, 5.0);
+        assert_eq!(range.lerp(0.5), 5.0);
+        assert_eq!(range.inverse_lerp(5.0), 0.0);
+        assert_eq!(range.inverse_lerp(10.0), 0.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_inverse_lerp_signed_wide() {
+        let range = ReversibleRange::new(-100i8, 100);
+        assert_eq!(range.inverse_lerp(-100), 0.0);
+        assert_eq!(range.inverse_lerp(0), 0.5);
+        assert_eq!(range.inverse_lerp(100), 1.0);
+
+        let range = ReversibleRange::new(127i8, -128);
+        assert_eq!(range.inverse_lerp(127), 0.0);
+        assert_eq!(range.inverse_lerp(-128), 1.0);
+        assert_eq!(range.inverse_lerp(-1), 128.0 / 255.0);
+    }
+    #[test]
+    fn test_iter_steps() {
+        let range = ReversibleRange::new(0u8, 10);
+        assert!(range.iter_steps(5).eq([0, 5, 10]));
+        assert!(range.iter_steps(3).eq([0, 3, 6, 9]));
+        assert!(range.iter_steps(20).eq([0]));
+        assert!(range.iter_steps(0).eq([0]));
+
+        // Steps that would overflow the type stop at the last position in range
+        let range = ReversibleRange::new(255u8, 100);
+        assert!(range.iter_steps(100).eq([255, 155]));
+        let range = ReversibleRange::new(200u8, 255);
+        assert!(range.iter_steps(50).eq([200, 250]));
+
+        // Negative steps are treated as a distance
+        let range = ReversibleRange::new(5i8, -5);
+        assert!(range.iter_steps(-5).eq([5, 0, -5]));
+    }
+
+    #[test]
+    fn test_split_signed_reversed() {
+        fn bounds(from: i8, to: i8, count: usize) -> [(i8, i8); 4] {
+            let mut out = [(0, 0); 4];
+            let range = ReversibleRange::new(from, to);
+            for (out, range) in out.iter_mut().zip(range.split(count).unwrap()) {
+                *out = (range.start, range.end);
+            }
+            out
         }
+
+        // Crossing zero
+        assert_eq!(bounds(10, -10, 4), [(10, 5), (5, 0), (0, -5), (-5, -10)]);
+        assert_eq!(bounds(5, -3, 4), [(5, 3), (3, 1), (1, -1), (-1, -3)]);
+
+        // Spanning more than `i8::MAX`, in both directions
+        assert_eq!(
+            bounds(100, -100, 4),
+            [(100, 50), (50, 0), (0, -50), (-50, -100)]
+        );
+        assert_eq!(
+            bounds(-100, 100, 4),
+            [(-100, -50), (-50, 0), (0, 50), (50, 100)]
+        );
+        assert_eq!(
+            bounds(i8::MAX, i8::MIN, 4),
+            [(127, 63), (63, 0), (0, -64), (-64, -128)]
+        );
+    }
+
+    #[test]
+    fn test_split_at() {
+        let range = ReversibleRange::new(0u8, 10);
+        assert_eq!(
+            range.split_at(3),
+            (ReversibleRange::new(0, 3), ReversibleRange::new(3, 10))
+        );
+        assert_eq!(
+            range.split_at(20),
+            (ReversibleRange::new(0, 10), ReversibleRange::new(10, 10))
+        );
+
+        let range = ReversibleRange::new(5i8, -5);
+        assert_eq!(
+            range.split_at(-10),
+            (ReversibleRange::new(5, -5), ReversibleRange::new(-5, -5))
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_chunks() {
+        let range = ReversibleRange::new(0u8, 10);
+        let chunks = range.chunks(3);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].start, 0);
+        assert_eq!(chunks[2].end, 10);
+        assert!(chunks.windows(2).all(|w| w[0].end == w[1].start));
+        assert!(chunks.iter().all(|c| (3..=4).contains(&c.len())));
+
+        let chunks = ReversibleRange::new(1.0, -1.0).chunks(2);
+        assert_eq!(
+            chunks,
+            vec![
+                ReversibleRange::new(1.0, 0.0),
+                ReversibleRange::new(0.0, -1.0)
+            ]
+        );
+
+        assert!(range.chunks(0).is_empty());
+        assert!(range.chunks(300).is_empty());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    #[allow(clippy::reversed_empty_ranges)] // Reversed ranges are valid in this crate
+    fn test_from_reversed_range_inclusive() {
+        let range = ReversibleRange::from(10.0..=0.0);
+        assert_eq!(range, ReversibleRange::new(10.0, 0.0));
+        assert!(range.is_reversed());
+        assert!(!range.is_empty());
+        assert!(range.contains(0.0) && range.contains(5.0) && range.contains(10.0));
+        assert!(!range.contains(10.5));
+
+        let range = ReversibleRange::from(200u8..=100);
+        assert_eq!(range.len(), 100);
+        assert_eq!(range.lerp(0.25), 175);
+
+        let bounds = 200u8..=100;
+        assert_eq!(ReversibleRange::from(&bounds), range);
+        assert_eq!(
+            ReversibleRange::from(&(0.0..=1.0)),
+            ReversibleRange::new(0.0, 1.0)
+        );
     }
 }
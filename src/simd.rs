@@ -0,0 +1,172 @@
+//! SIMD accelerated interpolation for `f32` value sets.
+//!
+//! On `x86_64` channels are blended 4 at a time using SSE, which is always available on that target.  
+//! On other targets these methods fall back to the scalar implementation.
+use crate::{InterpolationBucket, Numeric};
+
+#[cfg(not(feature = "no_std"))]
+use crate::{ChannelMode, LinearInterpolator, OutOfRange};
+
+/// Number of `f32` lanes in a SIMD register
+#[cfg(target_arch = "x86_64")]
+const LANES: usize = 4;
+
+impl<const N: usize, S: Numeric> InterpolationBucket<N, S, f32> {
+    /// Interpolate between the 2 value sets of this bucket at the given `s` value, using SIMD where available.
+    ///
+    /// Channels are blended as `lo + (hi - lo) * t` in `f32`, rather than through `f64` as in [`Self::interpolate`],
+    /// so results may differ from the scalar path by a few units in the last place.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::InterpolationBucket;
+    ///
+    /// let bucket = InterpolationBucket::new(0.0..=1.0, [0.0; 8], [8.0; 8]);
+    /// assert_eq!(bucket.interpolate_simd(0.5), [4.0; 8]);
+    /// ```
+    #[cfg(target_arch = "x86_64")]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn interpolate_simd(&self, s: S) -> [f32; N] {
+        use core::arch::x86_64::{
+            _mm_add_ps, _mm_loadu_ps, _mm_mul_ps, _mm_set1_ps, _mm_storeu_ps, _mm_sub_ps,
+        };
+
+        let t = self.progress(s) as f32;
+        let lo = self.values_lo();
+        let hi = self.values_hi();
+        let mut values = [0.0; N];
+
+        let chunks = N / LANES;
+
+        // SAFETY: SSE is part of the x86_64 baseline, so the intrinsics are always available,
+        // and `i + LANES <= N`, so every load and store is within the bounds of the arrays
+        unsafe {
+            let lanes = _mm_set1_ps(t);
+            for chunk in 0..chunks {
+                let i = chunk * LANES;
+                let lo = _mm_loadu_ps(lo.as_ptr().add(i));
+                let hi = _mm_loadu_ps(hi.as_ptr().add(i));
+                let blended = _mm_add_ps(lo, _mm_mul_ps(_mm_sub_ps(hi, lo), lanes));
+                _mm_storeu_ps(values.as_mut_ptr().add(i), blended);
+            }
+        }
+
+        for i in chunks * LANES..N {
+            values[i] = lo[i] + (hi[i] - lo[i]) * t;
+        }
+
+        values
+    }
+
+    /// Interpolate between the 2 value sets of this bucket at the given `s` value, using SIMD where available.
+    ///
+    /// SIMD is not available on this target, so this is equivalent to [`Self::interpolate`].
+    #[cfg(not(target_arch = "x86_64"))]
+    pub fn interpolate_simd(&self, s: S) -> [f32; N] {
+        self.interpolate(s)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<const N: usize, S: Numeric> LinearInterpolator<'_, N, S, f32> {
+    /// Interpolate between the value sets based on the given value, using SIMD where available.  
+    /// Channel modes, the output clamp and the out of range policy are applied as in [`Self::interpolate`].
+    ///
+    /// See [`InterpolationBucket::interpolate_simd`] for details.
+    pub fn interpolate_simd(&self, s: S) -> [f32; N] {
+        // Inputs outside of the range only need the scalar path when the policy is not to clamp them
+        if self.out_of_range() != OutOfRange::Clamp && !self.domain().contains(s.clone()) {
+            return self.interpolate(s);
+        }
+
+        let bucket = self.get_bucket(s.clone());
+        let rel_percent = bucket.progress(s.clone());
+        let mut values = bucket.interpolate_simd(s);
+
+        if let Some(modes) = self.channel_modes() {
+            for (i, value) in values.iter_mut().enumerate() {
+                if modes[i] == ChannelMode::Step {
+                    *value = if rel_percent < 0.5 {
+                        bucket.values_lo()[i]
+                    } else {
+                        bucket.values_hi()[i]
+                    };
+                }
+            }
+        }
+
+        if let Some((min, max)) = self.output_clamp() {
+            for (i, value) in values.iter_mut().enumerate() {
+                *value = value.clamp(min[i], max[i]);
+            }
+        }
+
+        values
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close<const N: usize>(actual: [f32; N], expected: [f32; N]) {
+        for (a, e) in actual.iter().zip(expected) {
+            let tolerance = f32::EPSILON * e.abs().max(1.0) * 4.0;
+            assert!((a - e).abs() <= tolerance, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn test_interpolate_simd() {
+        let lo: [f32; 11] = core::array::from_fn(|i| i as f32 * 10.0);
+        let hi: [f32; 11] = core::array::from_fn(|i| 1000.0 - i as f32 * 3.3);
+
+        let bucket = InterpolationBucket::new((0.0, 7.0), lo, hi);
+        let reversed = InterpolationBucket::new((7.0, 0.0), lo, hi);
+        for s in [-1.0, 0.0, 0.5, 3.3, 6.9, 7.0, 8.0] {
+            assert_close(bucket.interpolate_simd(s), bucket.interpolate(s));
+            assert_close(reversed.interpolate_simd(s), reversed.interpolate(s));
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_interpolator_simd() {
+        let interpolator = LinearInterpolator::new_with_modes(
+            0.0..=10.0,
+            &[[0.0, 0.0, 5.0, 1.0, 9.0], [10.0, 1.0, -5.0, 0.0, 3.0]],
+            [
+                ChannelMode::Continuous,
+                ChannelMode::Step,
+                ChannelMode::Continuous,
+                ChannelMode::Continuous,
+                ChannelMode::Step,
+            ],
+        )
+        .with_output_clamp([0.0; 5], [8.0; 5]);
+
+        for s in [0.0, 2.5, 5.0, 7.5, 10.0] {
+            assert_close(
+                interpolator.interpolate_simd(s),
+                interpolator.interpolate(s),
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_interpolator_simd_out_of_range() {
+        let interpolator = LinearInterpolator::new(0.0..=10.0, &[[0.0, 10.0], [10.0, 0.0]]);
+        assert_close(interpolator.interpolate_simd(12.0), [10.0, 0.0]);
+
+        let interpolator = interpolator.with_out_of_range(OutOfRange::Saturate);
+        for s in [-5.0, 12.0] {
+            assert_close(
+                interpolator.interpolate_simd(s),
+                interpolator.interpolate(s),
+            );
+        }
+        assert_close(interpolator.interpolate_simd(12.0), [12.0, -2.0]);
+    }
+}
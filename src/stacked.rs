@@ -0,0 +1,119 @@
+use crate::{LinearInterpolator, Numeric};
+
+/// A set of interpolators sharing one domain, whose outputs are concatenated into a single value set.
+///
+/// Useful for layered effects, where several interpolators are driven by the same input every frame.  
+/// The output holds `N` values per layer, in the order the layers were given.
+///
+/// # Example
+/// ```rust
+/// use lineic::{LinearInterpolator, StackedInterpolator};
+///
+/// let color = LinearInterpolator::new(0.0..=1.0, &[[0.0, 0.0, 0.0], [255.0, 128.0, 64.0]]);
+/// let glow = LinearInterpolator::new(0.0..=1.0, &[[1.0, 0.0, 0.0], [0.0, 0.0, 10.0]]);
+///
+/// let stacked = StackedInterpolator::new(vec![color, glow]);
+/// assert_eq!(stacked.interpolate(0.5), vec![127.5, 64.0, 32.0, 0.5, 0.0, 5.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackedInterpolator<'a, const N: usize, S: Numeric, T: Numeric> {
+    layers: Vec<LinearInterpolator<'a, N, S, T>>,
+}
+impl<'a, const N: usize, S: Numeric, T: Numeric> StackedInterpolator<'a, N, S, T> {
+    /// Stack the given interpolators, which must all cover the same domain.
+    ///
+    /// # Panics
+    /// Panics if the interpolators do not all share the same domain  
+    /// For a non-panic variant, see [`Self::try_new`]
+    pub fn new(layers: impl Into<Vec<LinearInterpolator<'a, N, S, T>>>) -> Self {
+        Self::try_new(layers)
+            .expect("Interpolators do not share a domain - Build each layer over the same range")
+    }
+
+    /// Stack the given interpolators, which must all cover the same domain.
+    ///
+    /// Returns None if the interpolators do not all share the same domain, including its orientation.  
+    /// This is the non-panic variant of [`Self::new`]
+    pub fn try_new(layers: impl Into<Vec<LinearInterpolator<'a, N, S, T>>>) -> Option<Self> {
+        let layers = layers.into();
+        if let Some(first) = layers.first() {
+            let domain = first.domain();
+            if layers.iter().any(|layer| layer.domain() != domain) {
+                return None;
+            }
+        }
+
+        Some(Self { layers })
+    }
+
+    /// Get the stacked interpolators, in output order.
+    #[must_use]
+    pub fn layers(&self) -> &[LinearInterpolator<'a, N, S, T>] {
+        &self.layers
+    }
+
+    /// Unwrap the stacked interpolators.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<LinearInterpolator<'a, N, S, T>> {
+        self.layers
+    }
+
+    /// The number of values produced by [`Self::interpolate`]; `N` per layer.
+    #[must_use]
+    pub fn channels(&self) -> usize {
+        self.layers.len() * N
+    }
+
+    /// Interpolate every layer at the given value, concatenating their outputs in order.
+    pub fn interpolate(&self, s: S) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.channels());
+        self.interpolate_into(s, &mut values);
+        values
+    }
+
+    /// Interpolate every layer at the given value, appending their outputs to `values` in order.  
+    /// This allows a buffer to be reused across frames, avoiding an allocation per call.
+    #[allow(clippy::needless_pass_by_value)] // Inputs are taken by value throughout the API, as they are usually Copy
+    pub fn interpolate_into(&self, s: S, values: &mut Vec<T>) {
+        for layer in &self.layers {
+            values.extend(layer.interpolate(s.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stacked_interpolate() {
+        let a = LinearInterpolator::new((10u8, 0), &[[0u8, 100], [100, 0]]);
+        let b = LinearInterpolator::new((10u8, 0), &[[50u8, 50], [0, 0], [50, 50]]);
+        let stacked = StackedInterpolator::new([a.clone(), b.clone()]);
+        assert_eq!(stacked.channels(), 4);
+
+        for s in [0, 3, 5, 10] {
+            let mut expected = a.interpolate(s).to_vec();
+            expected.extend(b.interpolate(s));
+            assert_eq!(stacked.interpolate(s), expected);
+        }
+
+        let mut buffer = vec![1];
+        stacked.interpolate_into(10, &mut buffer);
+        assert_eq!(buffer, [1, 0, 100, 50, 50]);
+
+        let empty = StackedInterpolator::<2, u8, u8>::new(vec![]);
+        assert!(empty.interpolate(5).is_empty());
+    }
+
+    #[test]
+    fn test_stacked_domain_mismatch() {
+        let a = LinearInterpolator::new((0u8, 10), &[[0u8], [100]]);
+        let b = LinearInterpolator::new((0u8, 20), &[[0u8], [100]]);
+        let reversed = LinearInterpolator::new((10u8, 0), &[[100u8], [0]]);
+
+        assert!(StackedInterpolator::try_new([a.clone(), b]).is_none());
+        assert!(StackedInterpolator::try_new([a.clone(), reversed]).is_none());
+        assert!(StackedInterpolator::try_new([a.clone(), a]).is_some());
+    }
+}
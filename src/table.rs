@@ -0,0 +1,121 @@
+use crate::{Interpolate, LinearInterpolator, Numeric, ReversibleRange};
+
+/// An interpolator backed by a precomputed lookup table, with one entry per whole value of `S` in its range.
+///
+/// Lookups are O(1), at the cost of memory proportional to the size of the range;  
+/// see [`LinearInterpolator::bake_table`] for details. This is ideal for 8-bit lookup tables.
+///
+/// Out of range inputs are clamped to the range, and fractional inputs are truncated towards the lowest value in the range.
+///
+/// # Example
+/// ```rust
+/// use lineic::{LinearInterpolator, TableInterpolator};
+///
+/// let gamma = LinearInterpolator::new(0u8..=255, &[[0u8], [10], [40], [100], [255]]);
+/// let table = TableInterpolator::new(&gamma);
+///
+/// for s in 0..=255 {
+///     assert_eq!(table.interpolate(s), gamma.interpolate(s));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableInterpolator<const N: usize, S: Numeric, T: Numeric> {
+    table: Vec<[T; N]>,
+    range: ReversibleRange<S>,
+}
+impl<const N: usize, S: Numeric, T: Numeric> TableInterpolator<N, S, T> {
+    /// Bake the given interpolator into a lookup table.
+    #[must_use]
+    pub fn new(interpolator: &LinearInterpolator<'_, N, S, T>) -> Self {
+        let table = interpolator.bake_table();
+        let range = interpolator.domain();
+        let range = if range.is_reversed() {
+            ReversibleRange::new(range.end, range.start)
+        } else {
+            range
+        };
+
+        Self { table, range }
+    }
+
+    /// Get the precomputed values, in ascending order of `s`.
+    pub fn table(&self) -> &[[T; N]] {
+        &self.table
+    }
+
+    /// Get the range covered by the table, in ascending order.
+    pub fn range(&self) -> &ReversibleRange<S> {
+        &self.range
+    }
+
+    /// Look up the values for the given input.  
+    /// Produces the same result as [`LinearInterpolator::interpolate`] for whole values of `S`.
+    pub fn interpolate(&self, s: S) -> [T; N] {
+        let s = s.clamp(self.range.start.clone(), self.range.end.clone());
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = (s.into_f64() - self.range.start.clone().into_f64()) as usize;
+        self.table[index.min(self.table.len() - 1)].clone()
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> Interpolate<N, S, T> for TableInterpolator<N, S, T> {
+    fn interpolate(&self, s: S) -> [T; N] {
+        TableInterpolator::interpolate(self, s)
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric> From<&LinearInterpolator<'_, N, S, T>>
+    for TableInterpolator<N, S, T>
+{
+    fn from(interpolator: &LinearInterpolator<'_, N, S, T>) -> Self {
+        Self::new(interpolator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_interpolate() {
+        let interpolator =
+            LinearInterpolator::new((1000u16, 10), &[[0u32, 100], [50, 0], [900, 1]]);
+        let table = TableInterpolator::new(&interpolator);
+        assert_eq!(table.table().len(), 991);
+        assert_eq!(table.range(), &ReversibleRange::new(10, 1000));
+
+        for s in 0..=1100 {
+            assert_eq!(table.interpolate(s), interpolator.interpolate(s));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_table_fractional() {
+        let interpolator = LinearInterpolator::new(0.0..=2.0, &[[0.0], [10.0]]);
+        let table = TableInterpolator::new(&interpolator);
+        assert_eq!(table.table(), &[[0.0], [5.0], [10.0]]);
+        assert_eq!(table.interpolate(1.5), [5.0]);
+        assert_eq!(table.interpolate(-3.0), [0.0]);
+        assert_eq!(table.interpolate(3.0), [10.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_table_signed_full_domain() {
+        let interpolator = LinearInterpolator::new(-128i8..=127, &[[0.0], [255.0]]);
+        let table = TableInterpolator::new(&interpolator);
+        assert_eq!(table.table().len(), 256);
+        assert_eq!(table.range(), &ReversibleRange::new(-128, 127));
+        for s in -128..=127 {
+            assert_eq!(table.interpolate(s), interpolator.interpolate(s));
+        }
+
+        let interpolator = LinearInterpolator::new(-100i8..=100, &[[0.0], [100.0], [200.0]]);
+        let table = TableInterpolator::new(&interpolator);
+        assert_eq!(table.interpolate(0), [100.0]);
+        assert_eq!(table.interpolate(-128), [0.0]);
+        assert_eq!(table.interpolate(127), [200.0]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{Interpolate, InterpolationBucket, LinearInterpolator, Numeric};
+
+/// A wrapper around a [`LinearInterpolator`] that carries a tag for each of its buckets.
+///
+/// Interpolation works as normal; the tags are just metadata, looked up with the same binary search
+/// as [`LinearInterpolator::get_bucket`]. Useful for labelling zones of a gauge or alert UI.
+///
+/// # Example
+/// ```rust
+/// use lineic::{LinearInterpolator, TaggedInterpolator};
+///
+/// let gauge = LinearInterpolator::new(0.0..=150.0, &[[0u8, 255, 0], [255, 255, 0], [255, 0, 0], [128, 0, 0]]);
+/// let gauge = TaggedInterpolator::new(gauge, vec!["safe", "warning", "danger"]);
+///
+/// assert_eq!(gauge.tag_at(25.0), &"safe");
+/// assert_eq!(gauge.tag_at(75.0), &"warning");
+/// assert_eq!(gauge.tag_at(125.0), &"danger");
+/// assert_eq!(gauge.interpolate(75.0), [255, 128, 0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedInterpolator<'a, const N: usize, S: Numeric, T: Numeric, Tag> {
+    interpolator: LinearInterpolator<'a, N, S, T>,
+    tags: Vec<Tag>,
+}
+impl<'a, const N: usize, S: Numeric, T: Numeric, Tag> TaggedInterpolator<'a, N, S, T, Tag> {
+    /// Attach a tag to each bucket of an interpolator.  
+    /// `tags` are matched to the buckets in order, as returned by [`LinearInterpolator::buckets`].
+    ///
+    /// # Panics
+    /// Panics if the number of tags does not match the number of buckets  
+    /// For a non-panic variant, see [`Self::try_new`]
+    pub fn new(interpolator: LinearInterpolator<'a, N, S, T>, tags: impl Into<Vec<Tag>>) -> Self {
+        Self::try_new(interpolator, tags).expect(
+            "Number of tags does not match the number of buckets - Provide one tag per bucket",
+        )
+    }
+
+    /// Attach a tag to each bucket of an interpolator.  
+    /// `tags` are matched to the buckets in order, as returned by [`LinearInterpolator::buckets`].
+    ///
+    /// Returns None if the number of tags does not match the number of buckets.  
+    /// This is the non-panic variant of [`Self::new`]
+    pub fn try_new(
+        interpolator: LinearInterpolator<'a, N, S, T>,
+        tags: impl Into<Vec<Tag>>,
+    ) -> Option<Self> {
+        let tags = tags.into();
+        if tags.len() != interpolator.buckets().len() {
+            return None;
+        }
+
+        Some(Self { interpolator, tags })
+    }
+
+    /// Get the wrapped interpolator.
+    pub fn interpolator(&self) -> &LinearInterpolator<'a, N, S, T> {
+        &self.interpolator
+    }
+
+    /// Get the tags, in the same order as the buckets.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Unwrap the interpolator and its tags.
+    pub fn into_inner(self) -> (LinearInterpolator<'a, N, S, T>, Vec<Tag>) {
+        (self.interpolator, self.tags)
+    }
+
+    /// Returns the tag of the bucket that contains the given value.
+    pub fn tag_at(&self, s: S) -> &Tag {
+        &self.tags[self.interpolator.bucket_index(s)]
+    }
+
+    /// Returns the bucket that contains the given value, along with its tag.
+    pub fn get_bucket(&self, s: S) -> (&InterpolationBucket<N, S, T>, &Tag) {
+        let index = self.interpolator.bucket_index(s);
+        (&self.interpolator.buckets()[index], &self.tags[index])
+    }
+
+    /// Interpolate between the value sets based on the given value.  
+    /// Produces the same result as [`LinearInterpolator::interpolate`].
+    pub fn interpolate(&self, s: S) -> [T; N] {
+        self.interpolator.interpolate(s)
+    }
+}
+
+impl<const N: usize, S: Numeric, T: Numeric, Tag> Interpolate<N, S, T>
+    for TaggedInterpolator<'_, N, S, T, Tag>
+{
+    fn interpolate(&self, s: S) -> [T; N] {
+        TaggedInterpolator::interpolate(self, s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Zone {
+        Cold,
+        Comfortable,
+        Hot,
+    }
+
+    #[test]
+    fn test_tag_at() {
+        let interpolator = LinearInterpolator::new((40i8, -20), &[[0u8], [50], [100], [255]]);
+        let tagged =
+            TaggedInterpolator::new(interpolator, vec![Zone::Hot, Zone::Comfortable, Zone::Cold]);
+
+        assert_eq!(tagged.tag_at(40), &Zone::Hot);
+        assert_eq!(tagged.tag_at(10), &Zone::Comfortable);
+        assert_eq!(tagged.tag_at(-15), &Zone::Cold);
+
+        // Out of range values resolve to the nearest bucket
+        assert_eq!(tagged.tag_at(100), &Zone::Hot);
+        assert_eq!(tagged.tag_at(-100), &Zone::Cold);
+
+        let (bucket, tag) = tagged.get_bucket(5);
+        assert_eq!(bucket, &tagged.interpolator().buckets()[1]);
+        assert_eq!(tag, &Zone::Comfortable);
+        assert_eq!(tagged.interpolate(5), tagged.interpolator().interpolate(5));
+    }
+
+    #[test]
+    fn test_tag_count_mismatch() {
+        let interpolator = LinearInterpolator::new(0.0..=1.0, &[[0.0], [1.0], [2.0]]);
+        assert!(TaggedInterpolator::try_new(interpolator.clone(), ["a"]).is_none());
+        assert!(TaggedInterpolator::try_new(interpolator.clone(), ["a", "b", "c"]).is_none());
+        assert!(TaggedInterpolator::try_new(interpolator, ["a", "b"]).is_some());
+    }
+}
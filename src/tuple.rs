@@ -0,0 +1,104 @@
+use crate::{LinearInterpolator, Numeric, ReversibleRange};
+
+macro_rules! impl_tuple_interpolator {
+    ($(#[$meta:meta])* $name:ident { $($field:ident: $t:ident = $idx:tt),+ }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name<'a, S: Numeric, $($t: Numeric),+> {
+            $($field: LinearInterpolator<'a, 1, S, $t>),+
+        }
+        impl<S: Numeric, $($t: Numeric),+> $name<'_, S, $($t),+> {
+            /// Create a new interpolator with the given range and value sets.
+            ///
+            /// The provided range will be divided into equal segments based on the number of value sets.
+            ///
+            /// # Panics
+            /// Panics if the number of value sets is too large to be represented by type S
+            ///
+            /// For a non-panic variant, see [`Self::try_new`]
+            pub fn new(range: impl Into<ReversibleRange<S>>, value_sets: &[($($t),+)]) -> Self {
+                Self::try_new(range, value_sets)
+                    .expect("Number of value sets too large to fit in type `S` - Reduce the number of data sets or use a larger type for `range`")
+            }
+
+            /// Create a new interpolator with the given range and value sets.
+            ///
+            /// The provided range will be divided into equal segments based on the number of value sets.
+            ///
+            /// Returns None if the number of value sets is too large to be represented by type S.
+            ///
+            /// This is the non-panic variant of [`Self::new`]
+            pub fn try_new(range: impl Into<ReversibleRange<S>>, value_sets: &[($($t),+)]) -> Option<Self> {
+                let range = range.into();
+                $(
+                    let values: Vec<_> = value_sets.iter().map(|v| [v.$idx.clone()]).collect();
+                    let $field = LinearInterpolator::try_new(range.clone(), &values)?;
+                )+
+                Some(Self { $($field),+ })
+            }
+
+            /// Interpolate between the value sets based on the given value.
+            ///
+            /// Each component is interpolated independently, as by [`LinearInterpolator::interpolate`].
+            pub fn interpolate(&self, s: S) -> ($($t),+) {
+                ($({
+                    let [value] = self.$field.interpolate(s.clone());
+                    value
+                }),+)
+            }
+        }
+    };
+}
+
+impl_tuple_interpolator!(
+    /// An interpolator for value sets made of 2 components with independent types.
+    ///
+    /// Use this when a value set mixes types, such as `(intensity: u8, temperature: f32)`, which `[T; N]` cannot express.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::Tuple2Interpolator;
+    ///
+    /// let interpolator = Tuple2Interpolator::new(0.0..=10.0, &[(0u8, 1500.0f32), (255, 6500.0)]);
+    /// assert_eq!(interpolator.interpolate(5.0), (127, 4000.0));
+    /// ```
+    Tuple2Interpolator { a: A = 0, b: B = 1 }
+);
+
+impl_tuple_interpolator!(
+    /// An interpolator for value sets made of 3 components with independent types.
+    ///
+    /// Use this when a value set mixes types, which `[T; N]` cannot express.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lineic::Tuple3Interpolator;
+    ///
+    /// let interpolator = Tuple3Interpolator::new(10u8..=0, &[(0u8, 0.0f64, -10i32), (100, 1.0, 10)]);
+    /// assert_eq!(interpolator.interpolate(5), (50, 0.5, 0));
+    /// ```
+    Tuple3Interpolator { a: A = 0, b: B = 1, c: C = 2 }
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_tuple_interpolate() {
+        let interpolator =
+            Tuple2Interpolator::new((100u8, 0), &[(0u8, 0.0f32), (100, 0.5), (200, 1.0)]);
+        assert_eq!(interpolator.interpolate(100), (0, 0.0));
+        assert_eq!(interpolator.interpolate(75), (50, 0.25));
+        assert_eq!(interpolator.interpolate(0), (200, 1.0));
+
+        let interpolator =
+            Tuple3Interpolator::new(0.0..=1.0, &[(10i8, 0u16, 0.0), (-10, 1000, 100.0)]);
+        assert_eq!(interpolator.interpolate(0.25), (5, 250, 25.0));
+
+        // Too many value sets for the range type
+        let value_sets = vec![(0u8, 0u8); 300];
+        assert!(Tuple2Interpolator::<u8, u8, u8>::try_new((0, 10), &value_sets).is_none());
+    }
+}